@@ -0,0 +1,189 @@
+//! Off-chain account decoder for the civic ledger program.
+//!
+//! This crate never touches the Solana runtime or Anchor's `#[program]`
+//! macro - it only knows how to turn raw account bytes back into
+//! dashboard/indexer-friendly JSON, mirroring the `solana-account-decoder`
+//! `UiAccount` / `parse_account_data` pattern. Anchor's Borsh layout already
+//! gives us the wire format; this module owns the client-facing view on top
+//! of it.
+
+use anchor_lang::AnchorDeserialize;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Length, in bytes, of an Anchor account discriminator.
+pub const DISCRIMINATOR_LEN: usize = 8;
+
+/// First 8 bytes of `sha256("account:AirQuality")`.
+pub const AIR_QUALITY_DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+    [23, 255, 46, 172, 6, 209, 161, 31];
+
+/// First 8 bytes of `sha256("account:Contract")`.
+pub const CONTRACT_DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+    [172, 138, 115, 242, 121, 67, 183, 26];
+
+/// Errors that can occur while decoding raw account bytes.
+#[derive(Error, Debug)]
+pub enum ParseAccountError {
+    #[error("account data too short to contain a discriminator")]
+    TooShort,
+    #[error("unrecognized account discriminator")]
+    UnknownDiscriminator,
+    #[error("failed to borsh-deserialize account data: {0}")]
+    BorshError(String),
+}
+
+/// A decoded account, tagged by its on-chain type.
+///
+/// Mirrors the enum-of-parsed-types shape `solana-account-decoder` returns
+/// from `parse_account_data`/`parse_full_account_data`, so a single call site
+/// can dispatch on discriminator without the caller needing to know the
+/// Borsh layout.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ParsedAccount {
+    AirQuality(UiAirQuality),
+    Contract(UiContract),
+}
+
+/// Decimal-string wrapper for an `f32` sensor reading.
+///
+/// JSON numbers are IEEE-754 doubles in most consumers (JS in particular),
+/// so round-tripping an `f32` as a bare JSON number risks precision drift
+/// between what the program stored and what a client displays. Rendering as
+/// a decimal string (the same `StringDecimals` trick `solana-account-decoder`
+/// uses for token amounts) keeps the exact on-chain value intact.
+pub type StringDecimals = String;
+
+fn f32_to_string_decimals(value: f32) -> StringDecimals {
+    value.to_string()
+}
+
+/// Client-facing view of an [`AirQuality`](civic_ledger::air_quality::AirQuality) account.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UiAirQuality {
+    pub location: String,
+    pub sensor_id: String,
+    pub authority: String,
+    pub aqi: u16,
+    pub pm25: StringDecimals,
+    pub pm10: StringDecimals,
+    pub co2: StringDecimals,
+    pub humidity: StringDecimals,
+    pub temperature: StringDecimals,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub update_count: u32,
+}
+
+/// Client-facing view of a [`Contract`](civic_ledger::contract::Contract) account.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UiContract {
+    pub name: String,
+    pub description: String,
+    pub contract_type: String,
+    pub authority: String,
+    pub is_active: bool,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub version: u32,
+    pub execution_count: u32,
+}
+
+/// Raw, Borsh-laid-out body of an `AirQuality` account (post-discriminator).
+///
+/// Kept private: callers only ever see the [`UiAirQuality`] view produced by
+/// [`parse_account_data`]/[`parse_full_account_data`].
+#[derive(AnchorDeserialize)]
+struct RawAirQuality {
+    location: String,
+    sensor_id: String,
+    authority: [u8; 32],
+    aqi: u16,
+    pm25: f32,
+    pm10: f32,
+    co2: f32,
+    humidity: f32,
+    temperature: f32,
+    created_at: i64,
+    updated_at: i64,
+    update_count: u32,
+}
+
+/// Raw, Borsh-laid-out body of a `Contract` account (post-discriminator).
+#[derive(AnchorDeserialize)]
+struct RawContract {
+    name: String,
+    description: String,
+    contract_type: String,
+    authority: [u8; 32],
+    is_active: bool,
+    created_at: i64,
+    updated_at: i64,
+    version: u32,
+    execution_count: u32,
+}
+
+/// Decodes a raw account's Borsh body into a [`ParsedAccount`], dispatching
+/// on the caller-supplied 8-byte Anchor discriminator (so a caller that
+/// already split `data[..8]` off, e.g. to look it up in a table first,
+/// doesn't pay for a second split here).
+pub fn parse_account_data(
+    discriminator: &[u8; DISCRIMINATOR_LEN],
+    data: &[u8],
+) -> Result<ParsedAccount, ParseAccountError> {
+    let mut body = data;
+
+    match discriminator {
+        d if d == &AIR_QUALITY_DISCRIMINATOR => {
+            let raw = RawAirQuality::deserialize(&mut body)
+                .map_err(|e| ParseAccountError::BorshError(e.to_string()))?;
+            Ok(ParsedAccount::AirQuality(UiAirQuality {
+                location: raw.location,
+                sensor_id: raw.sensor_id,
+                authority: bs58::encode(raw.authority).into_string(),
+                aqi: raw.aqi,
+                pm25: f32_to_string_decimals(raw.pm25),
+                pm10: f32_to_string_decimals(raw.pm10),
+                co2: f32_to_string_decimals(raw.co2),
+                humidity: f32_to_string_decimals(raw.humidity),
+                temperature: f32_to_string_decimals(raw.temperature),
+                created_at: raw.created_at,
+                updated_at: raw.updated_at,
+                update_count: raw.update_count,
+            }))
+        }
+        d if d == &CONTRACT_DISCRIMINATOR => {
+            let raw = RawContract::deserialize(&mut body)
+                .map_err(|e| ParseAccountError::BorshError(e.to_string()))?;
+            Ok(ParsedAccount::Contract(UiContract {
+                name: raw.name,
+                description: raw.description,
+                contract_type: raw.contract_type,
+                authority: bs58::encode(raw.authority).into_string(),
+                is_active: raw.is_active,
+                created_at: raw.created_at,
+                updated_at: raw.updated_at,
+                version: raw.version,
+                execution_count: raw.execution_count,
+            }))
+        }
+        _ => Err(ParseAccountError::UnknownDiscriminator),
+    }
+}
+
+/// Splits raw account bytes (discriminator + Borsh body) and decodes them
+/// via [`parse_account_data`]. The entry point for callers holding a whole
+/// account's bytes as fetched from the RPC, rather than an already-split
+/// discriminator and body.
+pub fn parse_full_account_data(data: &[u8]) -> Result<ParsedAccount, ParseAccountError> {
+    if data.len() < DISCRIMINATOR_LEN {
+        return Err(ParseAccountError::TooShort);
+    }
+
+    let (discriminator, body) = data.split_at(DISCRIMINATOR_LEN);
+    let discriminator: [u8; DISCRIMINATOR_LEN] = discriminator.try_into().unwrap();
+    parse_account_data(&discriminator, body)
+}
@@ -1,12 +1,51 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak::hashv;
 use crate::errors::CustomError;
-use crate::events::{AirQualityInitialized, AirQualityUpdated};
+use crate::events::{
+    AirQualityInitialized, AirQualitySealed, AirQualityUpdated, BatchOperationCompleted,
+    EconomicThresholdNotMet,
+};
+use crate::schedule::Schedule;
+use crate::sensor_blueprint::{BatchUpdate, ChangeRule, SensorBlueprint};
+use anchor_lang::AccountDeserialize;
+
+/// Maximum number of peaks the Merkle Mountain Range can hold, i.e. the
+/// maximum height of the accumulator. Appends fail once the new
+/// `leaf_count` would have more than 32 one-bits, so every count below
+/// `2^33 - 1` fits, which is unreachable in practice.
+pub const MMR_MAX_PEAKS: usize = 32;
+
+/// Number of samples retained in `AirQuality::history`'s rolling ring buffer.
+pub const HISTORY_CAPACITY: usize = 24;
+
+/// A single historical sample pushed into the rolling history ring buffer
+/// on every `update_data` call.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Default)]
+pub struct AirQualityHistorySample {
+    pub aqi: u16,
+    pub pm25: f32,
+    pub pm10: f32,
+    pub co2: f32,
+    pub timestamp: i64,
+}
+
+/// Lifecycle state of a sensor account, borrowed from the classic bank
+/// account pattern: open for business, frozen against further writes while
+/// still queryable, then closed once its rent has been reclaimed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum SensorStatus {
+    #[default]
+    Active,
+    Frozen,
+    Closed,
+}
 
 /// Air Quality sensor data account
 #[account]
+#[derive(Clone)]
 pub struct AirQuality {
     pub location: String,      // 50 chars max
-    pub sensor_id: String,     // 30 chars max  
+    pub sensor_id: String,     // 30 chars max
     pub authority: Pubkey,     // 32 bytes
     pub aqi: u16,              // 2 bytes
     pub pm25: f32,             // 4 bytes
@@ -17,9 +56,17 @@ pub struct AirQuality {
     pub created_at: i64,       // 8 bytes
     pub updated_at: i64,       // 8 bytes
     pub update_count: u32,     // 4 bytes - for economic optimization
+    pub history: [AirQualityHistorySample; HISTORY_CAPACITY], // fixed-capacity rolling window
+    pub history_head: u8,      // 1 byte - index of the next slot to overwrite
+    pub history_count: u8,     // 1 byte - number of populated slots (<= HISTORY_CAPACITY)
+    pub peaks: [[u8; 32]; MMR_MAX_PEAKS], // Merkle Mountain Range peaks, insertion-only
+    pub leaf_count: u64,       // 8 bytes - total readings ever committed to the MMR
+    pub status: SensorStatus,  // 1 byte - Active / Frozen / Closed
 }
 
 impl AirQuality {
+    const HISTORY_SAMPLE_LEN: usize = 2 + 4 + 4 + 4 + 8; // aqi, pm25, pm10, co2, timestamp
+
     pub const LEN: usize = 8 + // discriminator
         4 + 50 + // location (String)
         4 + 30 + // sensor_id (String)
@@ -32,7 +79,113 @@ impl AirQuality {
         4 + // temperature (f32)
         8 + // created_at (i64)
         8 + // updated_at (i64)
-        4; // update_count (u32)
+        4 + // update_count (u32)
+        (Self::HISTORY_SAMPLE_LEN * HISTORY_CAPACITY) + // history ring buffer
+        1 + // history_head (u8)
+        1 + // history_count (u8)
+        (32 * MMR_MAX_PEAKS) + // peaks (Merkle Mountain Range)
+        8 + // leaf_count (u64)
+        1; // status (SensorStatus)
+
+    /// Pushes a new sample into the rolling history ring buffer,
+    /// overwriting the oldest slot once full.
+    fn push_history(&mut self, aqi: u16, pm25: f32, pm10: f32, co2: f32, timestamp: i64) {
+        let head = self.history_head as usize;
+        self.history[head] = AirQualityHistorySample { aqi, pm25, pm10, co2, timestamp };
+        self.history_head = ((head + 1) % HISTORY_CAPACITY) as u8;
+        if (self.history_count as usize) < HISTORY_CAPACITY {
+            self.history_count += 1;
+        }
+    }
+
+    /// Returns the stored samples in oldest-to-newest order.
+    pub fn read_history(&self) -> Vec<AirQualityHistorySample> {
+        let count = self.history_count as usize;
+        let head = self.history_head as usize;
+        // Oldest sample is `count` slots behind `history_head` once the
+        // buffer has wrapped; before that it's simply index 0.
+        let start = (head + HISTORY_CAPACITY - count) % HISTORY_CAPACITY;
+        (0..count).map(|i| self.history[(start + i) % HISTORY_CAPACITY]).collect()
+    }
+
+    /// Hashes two child nodes into their parent, `hash(left || right)`.
+    fn mmr_hash_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        hashv(&[left, right]).to_bytes()
+    }
+
+    /// Hashes the canonical, fixed-width little-endian encoding of a reading
+    /// tuple into a leaf for the Merkle Mountain Range.
+    fn mmr_leaf_hash(
+        aqi: u16,
+        pm25: f32,
+        pm10: f32,
+        co2: f32,
+        humidity: f32,
+        temperature: f32,
+        updated_at: i64,
+    ) -> [u8; 32] {
+        let mut buf = Vec::with_capacity(2 + 4 + 4 + 4 + 4 + 4 + 8);
+        buf.extend_from_slice(&aqi.to_le_bytes());
+        buf.extend_from_slice(&pm25.to_le_bytes());
+        buf.extend_from_slice(&pm10.to_le_bytes());
+        buf.extend_from_slice(&co2.to_le_bytes());
+        buf.extend_from_slice(&humidity.to_le_bytes());
+        buf.extend_from_slice(&temperature.to_le_bytes());
+        buf.extend_from_slice(&updated_at.to_le_bytes());
+        hashv(&[&buf]).to_bytes()
+    }
+
+    /// Appends a reading to the insertion-only Merkle Mountain Range.
+    ///
+    /// Works like binary addition: the new leaf becomes a height-0 peak,
+    /// then while the two rightmost peaks share a height they're popped and
+    /// replaced by `hash(left || right)` at height+1 - exactly the carry
+    /// behaviour of incrementing `leaf_count` in binary. The peaks used
+    /// after the append are `self.peaks[..new_leaf_count.count_ones()]`,
+    /// ordered oldest/tallest first.
+    fn mmr_append(
+        &mut self,
+        aqi: u16,
+        pm25: f32,
+        pm10: f32,
+        co2: f32,
+        humidity: f32,
+        temperature: f32,
+        updated_at: i64,
+    ) -> Result<()> {
+        let mut node = Self::mmr_leaf_hash(aqi, pm25, pm10, co2, humidity, temperature, updated_at);
+        let mut carry = self.leaf_count;
+        let mut slot = self.leaf_count.count_ones() as usize;
+
+        while carry & 1 == 1 {
+            require!(slot > 0, CustomError::InvalidInput);
+            slot -= 1;
+            node = Self::mmr_hash_node(&self.peaks[slot], &node);
+            carry >>= 1;
+        }
+
+        require!(slot < MMR_MAX_PEAKS, CustomError::InvalidInput);
+        self.peaks[slot] = node;
+        self.leaf_count += 1;
+
+        Ok(())
+    }
+
+    /// Folds the current peaks right-to-left into a single "bag of peaks"
+    /// root, so light clients can be handed an `O(log n)` inclusion proof
+    /// for any past reading without trusting an indexer.
+    pub fn mmr_root(&self) -> [u8; 32] {
+        let count = self.leaf_count.count_ones() as usize;
+        if count == 0 {
+            return [0u8; 32];
+        }
+
+        let mut acc = self.peaks[count - 1];
+        for peak in self.peaks[..count - 1].iter().rev() {
+            acc = Self::mmr_hash_node(peak, &acc);
+        }
+        acc
+    }
 
     /// Validates air quality sensor data ranges
     pub fn validate_sensor_data(
@@ -68,6 +221,39 @@ impl AirQuality {
         aqi_change || pm25_change || pm10_change || co2_change || humidity_change || temp_change || time_threshold
     }
 
+    /// Same check as [`Self::is_significant_change`], but reading its cuts
+    /// from a live [`Schedule`] PDA instead of the fixed literals baked into
+    /// the program, when one is supplied. Falls back to the hardcoded
+    /// defaults when `schedule` is `None`, so the gated instructions keep
+    /// working for deployments that haven't initialized a `Schedule` yet.
+    pub fn is_significant_change_scheduled(
+        &self,
+        aqi: u16,
+        pm25: f32,
+        pm10: f32,
+        co2: f32,
+        humidity: f32,
+        temperature: f32,
+        schedule: Option<&Schedule>,
+    ) -> Result<bool> {
+        let Some(schedule) = schedule else {
+            return Ok(self.is_significant_change(aqi, pm25, pm10, co2, humidity, temperature));
+        };
+
+        let aqi_change = ((aqi as f32 - self.aqi as f32).abs() / self.aqi.max(1) as f32) * 100.0
+            > schedule.aqi_significance_pct;
+        let pm25_change = ((pm25 - self.pm25).abs() / self.pm25.max(1.0)) * 100.0 > schedule.pm25_significance_pct;
+        let pm10_change = ((pm10 - self.pm10).abs() / self.pm10.max(1.0)) * 100.0 > schedule.pm10_significance_pct;
+        let co2_change = ((co2 - self.co2).abs() / self.co2.max(1.0)) * 100.0 > schedule.co2_significance_pct;
+        let humidity_change = (humidity - self.humidity).abs() > schedule.humidity_significance_abs;
+        let temp_change = (temperature - self.temperature).abs() > schedule.temperature_significance_abs;
+
+        let time_threshold =
+            Clock::get()?.unix_timestamp - self.updated_at > schedule.time_tier_long_secs;
+
+        Ok(aqi_change || pm25_change || pm10_change || co2_change || humidity_change || temp_change || time_threshold)
+    }
+
     /// Updates sensor data with validation and economic optimization
     pub fn update_data(
         &mut self,
@@ -78,6 +264,8 @@ impl AirQuality {
         humidity: f32,
         temperature: f32,
     ) -> Result<()> {
+        require!(self.status == SensorStatus::Active, CustomError::SensorNotActive);
+
         // Validate input data
         Self::validate_sensor_data(aqi, pm25, pm10, co2, humidity, temperature)?;
 
@@ -90,9 +278,126 @@ impl AirQuality {
         self.temperature = temperature;
         self.updated_at = Clock::get()?.unix_timestamp;
         self.update_count += 1;
+        self.push_history(aqi, pm25, pm10, co2, self.updated_at);
+        self.mmr_append(aqi, pm25, pm10, co2, humidity, temperature, self.updated_at)?;
 
         Ok(())
     }
+
+    /// Checks an incoming reading against an explicit, caller-supplied set
+    /// of per-field thresholds (as opposed to [`Self::is_significant_change`],
+    /// which uses the fixed percentage/absolute cuts baked into the program).
+    ///
+    /// Returns `true` if at least one field moved by at least its configured
+    /// delta, meaning the update is economically worth writing on-chain.
+    pub fn min_delta_met(
+        &self,
+        aqi: u16,
+        pm25: f32,
+        pm10: f32,
+        co2: f32,
+        humidity: f32,
+        temperature: f32,
+        thresholds: &AirQualityThresholds,
+    ) -> bool {
+        let aqi_delta = (aqi as i32 - self.aqi as i32).unsigned_abs() as u16;
+
+        aqi_delta >= thresholds.min_aqi_delta
+            || (pm25 - self.pm25).abs() >= thresholds.min_pm25_delta
+            || (pm10 - self.pm10).abs() >= thresholds.min_pm10_delta
+            || (co2 - self.co2).abs() >= thresholds.min_co2_delta
+            || (humidity - self.humidity).abs() >= thresholds.min_humidity_delta
+            || (temperature - self.temperature).abs() >= thresholds.min_temperature_delta
+    }
+}
+
+/// `AirQuality`'s own fixed percentage/absolute cuts, expressed as
+/// `ChangeRule`s so they can run through the generic
+/// [`sensor_blueprint::is_significant_change`] pipeline. Field indices:
+/// 0 = aqi, 1 = pm25, 2 = pm10, 3 = co2, 4 = humidity, 5 = temperature.
+const AIR_QUALITY_THRESHOLDS: [ChangeRule; 7] = [
+    ChangeRule::Percent { field_idx: 0, pct: 5.0 },
+    ChangeRule::Percent { field_idx: 1, pct: 10.0 },
+    ChangeRule::Percent { field_idx: 2, pct: 10.0 },
+    ChangeRule::Percent { field_idx: 3, pct: 5.0 },
+    ChangeRule::Absolute { field_idx: 4, delta: 5.0 },
+    ChangeRule::Absolute { field_idx: 5, delta: 2.0 },
+    ChangeRule::TimeSeconds(86400),
+];
+
+impl SensorBlueprint for AirQuality {
+    type Fields = AirQualityReading;
+
+    fn validate(fields: &Self::Fields) -> Result<()> {
+        Self::validate_sensor_data(
+            fields.aqi, fields.pm25, fields.pm10, fields.co2, fields.humidity, fields.temperature,
+        )
+    }
+
+    fn thresholds() -> &'static [ChangeRule] {
+        &AIR_QUALITY_THRESHOLDS
+    }
+
+    fn field_value(fields: &Self::Fields, field_idx: u8) -> f32 {
+        match field_idx {
+            0 => fields.aqi as f32,
+            1 => fields.pm25,
+            2 => fields.pm10,
+            3 => fields.co2,
+            4 => fields.humidity,
+            5 => fields.temperature,
+            _ => 0.0,
+        }
+    }
+
+    fn current_value(&self, field_idx: u8) -> f32 {
+        match field_idx {
+            0 => self.aqi as f32,
+            1 => self.pm25,
+            2 => self.pm10,
+            3 => self.co2,
+            4 => self.humidity,
+            5 => self.temperature,
+            _ => 0.0,
+        }
+    }
+
+    fn last_updated_at(&self) -> i64 {
+        self.updated_at
+    }
+
+    fn apply(&mut self, fields: &Self::Fields) -> Result<()> {
+        self.update_data(fields.aqi, fields.pm25, fields.pm10, fields.co2, fields.humidity, fields.temperature)
+    }
+}
+
+/// Per-field delta thresholds for the opt-in "economic-threshold gated"
+/// update mode. Unlike [`AirQuality::is_significant_change`]'s fixed
+/// percentage cuts, these are supplied by the caller per-instruction so a
+/// deployment can tune sensitivity per sensor without a program upgrade.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct AirQualityThresholds {
+    pub min_aqi_delta: u16,
+    pub min_pm25_delta: f32,
+    pub min_pm10_delta: f32,
+    pub min_co2_delta: f32,
+    pub min_humidity_delta: f32,
+    pub min_temperature_delta: f32,
+}
+
+/// Maximum number of sensors a single `update_air_quality_batch` call may
+/// touch, bounding compute/account-read cost per transaction.
+pub const BATCH_UPDATE_LIMIT: usize = 10;
+
+/// A single sensor reading submitted as part of a batch update.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct AirQualityReading {
+    pub aqi: u16,
+    pub pm25: f32,
+    pub pm10: f32,
+    pub co2: f32,
+    pub humidity: f32,
+    pub temperature: f32,
 }
 
 /// Context for initializing air quality sensor
@@ -122,7 +427,24 @@ pub struct UpdateAirQuality<'info> {
         has_one = authority @ CustomError::UnauthorizedAccess
     )]
     pub air_quality: Account<'info, AirQuality>,
-    
+
+    pub authority: Signer<'info>,
+
+    /// Live economic-significance cuts; reads the hardcoded defaults when
+    /// no `Schedule` has been initialized for this deployment yet.
+    #[account(seeds = [b"schedule"], bump)]
+    pub schedule: Option<Account<'info, Schedule>>,
+}
+
+/// Context for the opt-in, explicitly-thresholded update mode
+#[derive(Accounts)]
+pub struct UpdateAirQualityThresholded<'info> {
+    #[account(
+        mut,
+        has_one = authority @ CustomError::UnauthorizedAccess
+    )]
+    pub air_quality: Account<'info, AirQuality>,
+
     pub authority: Signer<'info>,
 }
 
@@ -146,7 +468,46 @@ pub struct BatchUpdateAirQuality<'info> {
         has_one = authority @ CustomError::UnauthorizedAccess
     )]
     pub air_quality_3: Account<'info, AirQuality>,
-    
+
+    pub authority: Signer<'info>,
+
+    /// Live economic-significance cuts; reads the hardcoded defaults when
+    /// no `Schedule` has been initialized for this deployment yet.
+    #[account(seeds = [b"schedule"], bump)]
+    pub schedule: Option<Account<'info, Schedule>>,
+}
+
+/// Context for batch updating an arbitrary number of air quality sensors
+/// passed via `ctx.remaining_accounts`, rather than a fixed set of named
+/// account fields.
+#[derive(Accounts)]
+pub struct UpdateAirQualityBatch<'info> {
+    pub authority: Signer<'info>,
+}
+
+/// Context for freezing a sensor against further writes ahead of retirement.
+#[derive(Accounts)]
+pub struct FreezeAirQuality<'info> {
+    #[account(
+        mut,
+        has_one = authority @ CustomError::UnauthorizedAccess
+    )]
+    pub air_quality: Account<'info, AirQuality>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Context for closing a frozen sensor and reclaiming its rent.
+#[derive(Accounts)]
+pub struct CloseAirQuality<'info> {
+    #[account(
+        mut,
+        has_one = authority @ CustomError::UnauthorizedAccess,
+        close = authority
+    )]
+    pub air_quality: Account<'info, AirQuality>,
+
+    #[account(mut)]
     pub authority: Signer<'info>,
 }
 
@@ -168,7 +529,13 @@ impl<'info> InitializeAirQuality<'info> {
         air_quality.created_at = Clock::get()?.unix_timestamp;
         air_quality.updated_at = Clock::get()?.unix_timestamp;
         air_quality.update_count = 0;
-        
+        air_quality.history = [AirQualityHistorySample::default(); HISTORY_CAPACITY];
+        air_quality.history_head = 0;
+        air_quality.history_count = 0;
+        air_quality.peaks = [[0u8; 32]; MMR_MAX_PEAKS];
+        air_quality.leaf_count = 0;
+        air_quality.status = SensorStatus::Active;
+
         // Initialize with default safe values
         air_quality.aqi = 0;
         air_quality.pm25 = 0.0;
@@ -198,10 +565,12 @@ impl<'info> UpdateAirQuality<'info> {
         humidity: f32,
         temperature: f32,
     ) -> Result<()> {
+        let schedule = self.schedule.as_deref();
         let air_quality = &mut self.air_quality;
-        
-        // Economic optimization: only update if change is significant
-        if !air_quality.is_significant_change(aqi, pm25, pm10, co2, humidity, temperature) {
+
+        // Economic optimization: only update if change is significant,
+        // judged against the live Schedule when one exists.
+        if !air_quality.is_significant_change_scheduled(aqi, pm25, pm10, co2, humidity, temperature, schedule)? {
             msg!("Change not significant enough for blockchain update");
             return Ok(());
         }
@@ -217,12 +586,60 @@ impl<'info> UpdateAirQuality<'info> {
             humidity,
             temperature,
             timestamp: air_quality.updated_at,
+            mmr_root: air_quality.mmr_root(),
         });
         
         Ok(())
     }
 }
 
+impl<'info> UpdateAirQualityThresholded<'info> {
+    /// Applies a reading only if it moved at least one field past its
+    /// caller-supplied threshold, otherwise emits `EconomicThresholdNotMet`
+    /// and leaves the account (including `updated_at`) untouched. This lets
+    /// a deployment cut rent/compute churn for sensors that report
+    /// near-identical values every tick, without relying on the program's
+    /// fixed percentage cuts.
+    pub fn process(
+        &mut self,
+        aqi: u16,
+        pm25: f32,
+        pm10: f32,
+        co2: f32,
+        humidity: f32,
+        temperature: f32,
+        thresholds: AirQualityThresholds,
+    ) -> Result<()> {
+        let air_quality = &mut self.air_quality;
+
+        if !air_quality.min_delta_met(aqi, pm25, pm10, co2, humidity, temperature, &thresholds) {
+            emit!(EconomicThresholdNotMet {
+                account: air_quality.key(),
+                operation: "update_air_quality".to_string(),
+                reason: "no field moved past its configured threshold".to_string(),
+                timestamp: Clock::get()?.unix_timestamp,
+            });
+            return Ok(());
+        }
+
+        air_quality.update_data(aqi, pm25, pm10, co2, humidity, temperature)?;
+
+        emit!(AirQualityUpdated {
+            air_quality: air_quality.key(),
+            aqi,
+            pm25,
+            pm10,
+            co2,
+            humidity,
+            temperature,
+            timestamp: air_quality.updated_at,
+            mmr_root: air_quality.mmr_root(),
+        });
+
+        Ok(())
+    }
+}
+
 /// Batch update for economic efficiency
 impl<'info> BatchUpdateAirQuality<'info> {
     pub fn process(
@@ -230,17 +647,18 @@ impl<'info> BatchUpdateAirQuality<'info> {
         sensor_data: Vec<(u16, f32, f32, f32, f32, f32)>, // (aqi, pm25, pm10, co2, humidity, temp)
     ) -> Result<()> {
         require!(sensor_data.len() <= 3, CustomError::InvalidInput);
-        
+
+        let schedule = self.schedule.as_deref();
         let mut sensors = [
             &mut self.air_quality_1,
             &mut self.air_quality_2,
             &mut self.air_quality_3,
         ];
-        
+
         for (i, (aqi, pm25, pm10, co2, humidity, temperature)) in sensor_data.iter().enumerate() {
             if i < sensors.len() {
                 let sensor = &mut sensors[i];
-                if sensor.is_significant_change(*aqi, *pm25, *pm10, *co2, *humidity, *temperature) {
+                if sensor.is_significant_change_scheduled(*aqi, *pm25, *pm10, *co2, *humidity, *temperature, schedule)? {
                     sensor.update_data(*aqi, *pm25, *pm10, *co2, *humidity, *temperature)?;
                     
                     emit!(AirQualityUpdated {
@@ -252,11 +670,89 @@ impl<'info> BatchUpdateAirQuality<'info> {
                         humidity: *humidity,
                         temperature: *temperature,
                         timestamp: sensor.updated_at,
+                        mmr_root: sensor.mmr_root(),
                     });
                 }
             }
         }
-        
+
+        Ok(())
+    }
+}
+
+impl<'info> UpdateAirQualityBatch<'info> {
+    /// Applies a reading to each `AirQuality` PDA passed via
+    /// `ctx.remaining_accounts`, re-deriving and checking the PDA seeds and
+    /// `authority` for every account rather than trusting the caller's
+    /// ordering. A bad reading (wrong PDA, wrong authority, or a change too
+    /// small to be significant) is skipped rather than aborting the whole
+    /// batch, so one bad sensor doesn't roll back the rest.
+    pub fn process(&mut self, remaining_accounts: &[AccountInfo<'info>], readings: Vec<AirQualityReading>) -> Result<()> {
+        require!(readings.len() <= BATCH_UPDATE_LIMIT, CustomError::BatchOperationLimitExceeded);
+
+        let batch = BatchUpdate::<AirQuality, BATCH_UPDATE_LIMIT>::new(self.authority.key(), remaining_accounts);
+
+        let accounts_affected = batch.process(
+            readings,
+            |air_quality| air_quality.authority,
+            |account_info, air_quality| {
+                let (expected_key, _bump) = Pubkey::find_program_address(
+                    &[b"air_quality", air_quality.location.as_bytes(), air_quality.sensor_id.as_bytes()],
+                    &crate::ID,
+                );
+                expected_key == *account_info.key
+            },
+            |account_info, air_quality| {
+                emit!(AirQualityUpdated {
+                    air_quality: *account_info.key,
+                    aqi: air_quality.aqi,
+                    pm25: air_quality.pm25,
+                    pm10: air_quality.pm10,
+                    co2: air_quality.co2,
+                    humidity: air_quality.humidity,
+                    temperature: air_quality.temperature,
+                    timestamp: air_quality.updated_at,
+                    mmr_root: air_quality.mmr_root(),
+                });
+            },
+        )?;
+
+        emit!(BatchOperationCompleted {
+            operation_type: "update_air_quality_batch".to_string(),
+            accounts_affected,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> FreezeAirQuality<'info> {
+    /// Blocks all further `update_data` calls while leaving the account and
+    /// its Merklized history readable, ahead of an eventual `close`.
+    pub fn process(&mut self) -> Result<()> {
+        require!(self.air_quality.status == SensorStatus::Active, CustomError::SensorNotActive);
+        self.air_quality.status = SensorStatus::Frozen;
+        self.air_quality.updated_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+}
+
+impl<'info> CloseAirQuality<'info> {
+    /// Verifies the sensor is frozen, emits a final `AirQualitySealed` event
+    /// carrying the last accumulator root, and relies on `close = authority`
+    /// to return the account's rent lamports.
+    pub fn process(&mut self) -> Result<()> {
+        require!(self.air_quality.status == SensorStatus::Frozen, CustomError::SensorNotFrozen);
+
+        emit!(AirQualitySealed {
+            air_quality: self.air_quality.key(),
+            final_mmr_root: self.air_quality.mmr_root(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        self.air_quality.status = SensorStatus::Closed;
+
         Ok(())
     }
 }
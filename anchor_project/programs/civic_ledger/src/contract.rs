@@ -1,6 +1,90 @@
 use anchor_lang::prelude::*;
 use crate::errors::CustomError;
-use crate::events::{ContractInitialized, ContractStatusUpdated, ContractUpdated, ContractExecuted};
+use crate::events::{
+    BatchOperationCompleted, ContractExpired, ContractInitialized, ContractStatusUpdated, ContractUpdated,
+    ContractExecuted, ContractExecutedWithParams,
+};
+use crate::program_state::{require_not_paused, ProgramState};
+use crate::schedule::Schedule;
+use crate::params::{decode_params, ParamType, PARAM_TYPE_BYTES, MAX_PARAMS};
+use anchor_lang::AccountDeserialize;
+
+/// A release condition gating `Contract::execute`, modeled on the Solana
+/// Budget contract's payment-plan primitives: a witness signature, an
+/// elapsed-time trigger, or a boolean composition of both.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub enum Condition {
+    /// Satisfied once `Clock::get()?.unix_timestamp >= t`, the stored deadline.
+    Timestamp(i64),
+    /// Satisfied once this key co-signs an `apply_witness` instruction.
+    Signature(Pubkey),
+    /// Satisfied once both sub-conditions are.
+    And(Box<Condition>, Box<Condition>),
+    /// Satisfied once either sub-condition is.
+    Or(Box<Condition>, Box<Condition>),
+}
+
+/// Maximum number of top-level conditions a contract's `pending` plan may
+/// hold at once, bounding `Contract::LEN`.
+pub const MAX_PENDING_CONDITIONS: usize = 4;
+
+/// Maximum nesting depth of an `And`/`Or` condition tree.
+/// `validate_condition_depth` rejects any node (leaf or internal) at
+/// depth >= this, so the deepest a leaf can sit is `MAX_CONDITION_DEPTH - 1`.
+pub const MAX_CONDITION_DEPTH: u8 = 4;
+
+/// Per-item byte budget reserved for a single `pending` entry within
+/// `Contract::LEN`, sized for the real worst case `MAX_CONDITION_DEPTH`
+/// allows: a full binary `And`/`Or` tree with leaves at depth
+/// `MAX_CONDITION_DEPTH - 1`, every leaf the largest variant
+/// (`Signature(Pubkey)`, 1-byte tag + 32-byte key). At depth 4 that's 7
+/// internal nodes (1-byte tag each) plus 8 leaves (33 bytes each).
+pub const CONDITION_BYTES: usize = 7 * 1 + 8 * (1 + 32);
+
+/// Walks a condition tree, rejecting anything too deep for `apply_witness`
+/// to safely collapse.
+pub fn validate_condition_depth(condition: &Condition, depth: u8) -> Result<()> {
+    require!(depth < MAX_CONDITION_DEPTH, CustomError::InvalidInput);
+    match condition {
+        Condition::Timestamp(_) | Condition::Signature(_) => Ok(()),
+        Condition::And(a, b) | Condition::Or(a, b) => {
+            validate_condition_depth(a, depth + 1)?;
+            validate_condition_depth(b, depth + 1)
+        }
+    }
+}
+
+/// Collapses a condition against the current clock and an incoming witness,
+/// returning `None` once it's fully satisfied or the simplified remainder
+/// otherwise. `And` keeps whichever sub-condition(s) aren't yet satisfied;
+/// `Or` is satisfied as soon as either sub-condition is.
+pub fn collapse_condition(condition: Condition, now: i64, witness: Pubkey) -> Option<Condition> {
+    match condition {
+        Condition::Timestamp(at) => (now < at).then_some(Condition::Timestamp(at)),
+        Condition::Signature(key) => (key != witness).then_some(Condition::Signature(key)),
+        Condition::And(a, b) => {
+            match (collapse_condition(*a, now, witness), collapse_condition(*b, now, witness)) {
+                (None, None) => None,
+                (None, Some(remaining)) | (Some(remaining), None) => Some(remaining),
+                (Some(a), Some(b)) => Some(Condition::And(Box::new(a), Box::new(b))),
+            }
+        }
+        Condition::Or(a, b) => {
+            match (collapse_condition(*a, now, witness), collapse_condition(*b, now, witness)) {
+                (None, _) | (_, None) => None,
+                (Some(a), Some(b)) => Some(Condition::Or(Box::new(a), Box::new(b))),
+            }
+        }
+    }
+}
+
+/// What `poke()` applies once a contract's `expires_at` deadline has passed,
+/// borrowed from Marlowe's timeout-continuation semantics.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum TimeoutAction {
+    Deactivate,
+    TransferAuthority(Pubkey),
+}
 
 /// Smart city contract account
 #[account]
@@ -14,6 +98,11 @@ pub struct Contract {
     pub updated_at: i64,       // 8 bytes
     pub version: u32,          // 4 bytes - for versioning
     pub execution_count: u32,  // 4 bytes - for tracking usage
+    pub pending: Vec<Condition>, // release conditions that must collapse to empty before `execute`
+    pub expires_at: Option<i64>, // wall-clock deadline for `timeout_action`, if any
+    pub timeout_action: Option<TimeoutAction>, // what `poke()`/`execute()` apply once `expires_at` has passed
+    pub accumulated_weight: u64, // 8 bytes - summed execution weight, capped by Schedule::max_weight_per_contract
+    pub param_schema: Vec<ParamType>, // declared calling convention for `execute_with_params`
 }
 
 impl Contract {
@@ -26,7 +115,35 @@ impl Contract {
         8 + // created_at (i64)
         8 + // updated_at (i64)
         4 + // version (u32)
-        4; // execution_count (u32)
+        4 + // execution_count (u32)
+        4 + (CONDITION_BYTES * MAX_PENDING_CONDITIONS) + // pending (Vec<Condition>, capped)
+        (1 + 8) + // expires_at (Option<i64>)
+        (1 + 1 + 32) + // timeout_action (Option<TimeoutAction>, largest variant is TransferAuthority(Pubkey))
+        8 + // accumulated_weight (u64)
+        4 + (PARAM_TYPE_BYTES * MAX_PARAMS); // param_schema (Vec<ParamType>, capped)
+
+    /// Applies `timeout_action` once `expires_at` has passed, consuming both
+    /// fields so a later poke/execute doesn't reapply it. Returns whether a
+    /// timeout fired just now.
+    pub fn apply_expiry_if_due(&mut self) -> Result<bool> {
+        let Some(expires_at) = self.expires_at else { return Ok(false) };
+        if Clock::get()?.unix_timestamp < expires_at {
+            return Ok(false);
+        }
+        let Some(action) = self.timeout_action else { return Ok(false) };
+
+        match action {
+            TimeoutAction::Deactivate => self.is_active = false,
+            TimeoutAction::TransferAuthority(new_authority) => self.authority = new_authority,
+        }
+
+        self.expires_at = None;
+        self.timeout_action = None;
+        self.updated_at = Clock::get()?.unix_timestamp;
+        self.version += 1;
+
+        Ok(true)
+    }
 
     /// Validates contract input data
     pub fn validate_contract_data(
@@ -83,12 +200,23 @@ impl Contract {
         Ok(changed)
     }
 
-    /// Executes contract (increments usage counter)
-    pub fn execute(&mut self) -> Result<()> {
+    /// Executes contract (increments usage counter), charging this call's
+    /// contract-type weight against `accumulated_weight` and rejecting it
+    /// once that exceeds the schedule's `max_weight_per_contract`. `schedule`
+    /// is required - the cap has no teeth if a caller can opt out of it by
+    /// leaving the account off the instruction. Returns the weight charged,
+    /// for the caller to attach to `ContractExecuted`.
+    pub fn execute(&mut self, schedule: &Schedule) -> Result<u64> {
         require!(self.is_active, CustomError::ContractInactive);
+
+        let weight = schedule.weight_for(&self.contract_type);
+        let accumulated = self.accumulated_weight.saturating_add(weight);
+        require!(accumulated <= schedule.max_weight_per_contract, CustomError::WeightExceeded);
+        self.accumulated_weight = accumulated;
+
         self.execution_count += 1;
         self.updated_at = Clock::get()?.unix_timestamp;
-        Ok(())
+        Ok(weight)
     }
 }
 
@@ -107,8 +235,14 @@ pub struct InitializeContract<'info> {
     
     #[account(mut)]
     pub authority: Signer<'info>,
-    
+
     pub system_program: Program<'info, System>,
+
+    /// Kill-switch guard, required so the pause flag can't be bypassed by
+    /// simply omitting the account; governance must run
+    /// `initialize_program_state` before any guarded instruction will run.
+    #[account(seeds = [b"program_state"], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
 }
 
 /// Context for updating contract status
@@ -119,8 +253,14 @@ pub struct UpdateContract<'info> {
         has_one = authority @ CustomError::UnauthorizedAccess
     )]
     pub contract: Account<'info, Contract>,
-    
+
     pub authority: Signer<'info>,
+
+    /// Kill-switch guard, required so the pause flag can't be bypassed by
+    /// simply omitting the account; governance must run
+    /// `initialize_program_state` before any guarded instruction will run.
+    #[account(seeds = [b"program_state"], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
 }
 
 /// Context for updating contract details
@@ -131,8 +271,14 @@ pub struct UpdateContractDetails<'info> {
         has_one = authority @ CustomError::UnauthorizedAccess
     )]
     pub contract: Account<'info, Contract>,
-    
+
     pub authority: Signer<'info>,
+
+    /// Kill-switch guard, required so the pause flag can't be bypassed by
+    /// simply omitting the account; governance must run
+    /// `initialize_program_state` before any guarded instruction will run.
+    #[account(seeds = [b"program_state"], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
 }
 
 /// Context for executing a contract
@@ -143,28 +289,117 @@ pub struct ExecuteContract<'info> {
         has_one = authority @ CustomError::UnauthorizedAccess
     )]
     pub contract: Account<'info, Contract>,
-    
+
     pub authority: Signer<'info>,
+
+    /// Kill-switch guard, required so the pause flag can't be bypassed by
+    /// simply omitting the account; governance must run
+    /// `initialize_program_state` before any guarded instruction will run.
+    #[account(seeds = [b"program_state"], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    /// Live per-contract-type execution weight table, required so the
+    /// `max_weight_per_contract` cap can't be bypassed by simply omitting
+    /// the account; falls back to `DEFAULT_EXECUTION_WEIGHT` only for
+    /// contract types absent from `contract_weights`, not for a missing
+    /// `Schedule` itself.
+    #[account(seeds = [b"schedule"], bump = schedule.bump)]
+    pub schedule: Account<'info, Schedule>,
 }
 
-/// Context for batch contract operations (economic optimization)
+/// Context for executing a contract with ABI-style typed call data,
+/// decoded against the contract's own declared `param_schema`.
+#[derive(Accounts)]
+pub struct ExecuteContractWithParams<'info> {
+    #[account(
+        mut,
+        has_one = authority @ CustomError::UnauthorizedAccess
+    )]
+    pub contract: Account<'info, Contract>,
+
+    pub authority: Signer<'info>,
+
+    /// Kill-switch guard, required so the pause flag can't be bypassed by
+    /// simply omitting the account; governance must run
+    /// `initialize_program_state` before any guarded instruction will run.
+    #[account(seeds = [b"program_state"], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    /// Live per-contract-type execution weight table; see
+    /// [`ExecuteContract::schedule`].
+    #[account(seeds = [b"schedule"], bump = schedule.bump)]
+    pub schedule: Account<'info, Schedule>,
+}
+
+/// Maximum number of contracts a single `BatchContractOperation` call may
+/// touch, bounding compute/account-read cost per transaction.
+pub const BATCH_CONTRACT_LIMIT: usize = 16;
+
+/// Context for batch contract operations (economic optimization). The
+/// target `Contract` accounts are passed via `ctx.remaining_accounts`
+/// rather than a fixed set of named fields, the same generalization
+/// `update_air_quality_batch` applies to sensors.
 #[derive(Accounts)]
 pub struct BatchContractOperation<'info> {
+    pub authority: Signer<'info>,
+
+    /// Kill-switch guard, required so the pause flag can't be bypassed by
+    /// simply omitting the account; governance must run
+    /// `initialize_program_state` before any guarded instruction will run.
+    #[account(seeds = [b"program_state"], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    /// Live per-contract-type execution weight table; see
+    /// [`ExecuteContract::schedule`].
+    #[account(seeds = [b"schedule"], bump = schedule.bump)]
+    pub schedule: Account<'info, Schedule>,
+}
+
+/// Context for arming a contract's release-condition plan.
+#[derive(Accounts)]
+pub struct ArmContract<'info> {
     #[account(
         mut,
         has_one = authority @ CustomError::UnauthorizedAccess
     )]
-    pub contract_1: Account<'info, Contract>,
-    
+    pub contract: Account<'info, Contract>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Context for applying a witness signature against a contract's pending
+/// conditions. Any signer may call this - it only collapses conditions
+/// that actually match `witness`'s key or are time-elapsed, it can't forge
+/// satisfaction of someone else's `Signature` condition.
+#[derive(Accounts)]
+pub struct ApplyWitness<'info> {
+    #[account(mut)]
+    pub contract: Account<'info, Contract>,
+
+    pub witness: Signer<'info>,
+}
+
+/// Context for setting or clearing a contract's timeout deadline.
+#[derive(Accounts)]
+pub struct SetContractTimeout<'info> {
     #[account(
         mut,
         has_one = authority @ CustomError::UnauthorizedAccess
     )]
-    pub contract_2: Account<'info, Contract>,
-    
+    pub contract: Account<'info, Contract>,
+
     pub authority: Signer<'info>,
 }
 
+/// Context for the permissionless timeout-expiry poke. Anyone may call this
+/// - it only ever applies a deadline the authority already armed, it can't
+/// be used to deactivate or reassign a contract on its own.
+#[derive(Accounts)]
+pub struct PokeContract<'info> {
+    #[account(mut)]
+    pub contract: Account<'info, Contract>,
+}
+
 /// Contract instruction handlers
 impl<'info> InitializeContract<'info> {
     pub fn process(
@@ -172,8 +407,12 @@ impl<'info> InitializeContract<'info> {
         name: String,
         description: String,
         contract_type: String,
+        param_schema: Vec<ParamType>,
     ) -> Result<()> {
+        require_not_paused(&self.program_state)?;
+
         Contract::validate_contract_data(&name, &description, &contract_type)?;
+        require!(param_schema.len() <= MAX_PARAMS, CustomError::InvalidInput);
 
         let contract = &mut self.contract;
         contract.name = name.clone();
@@ -185,7 +424,12 @@ impl<'info> InitializeContract<'info> {
         contract.updated_at = Clock::get()?.unix_timestamp;
         contract.version = 1;
         contract.execution_count = 0;
-        
+        contract.pending = Vec::new();
+        contract.expires_at = None;
+        contract.timeout_action = None;
+        contract.accumulated_weight = 0;
+        contract.param_schema = param_schema;
+
         emit!(ContractInitialized {
             contract: contract.key(),
             name,
@@ -200,6 +444,8 @@ impl<'info> InitializeContract<'info> {
 
 impl<'info> UpdateContract<'info> {
     pub fn process_status_update(&mut self, is_active: bool) -> Result<()> {
+        require_not_paused(&self.program_state)?;
+
         let contract = &mut self.contract;
         
         // Economic optimization: only update if status actually changes
@@ -225,8 +471,10 @@ impl<'info> UpdateContractDetails<'info> {
         description: Option<String>,
         contract_type: Option<String>,
     ) -> Result<()> {
+        require_not_paused(&self.program_state)?;
+
         let contract = &mut self.contract;
-        
+
         // Economic optimization: only emit event if something actually changed
         let changed = contract.update_contract(name.clone(), description.clone(), contract_type.clone())?;
         
@@ -247,44 +495,231 @@ impl<'info> UpdateContractDetails<'info> {
 
 impl<'info> ExecuteContract<'info> {
     pub fn process(&mut self) -> Result<()> {
+        require_not_paused(&self.program_state)?;
+        self.contract.apply_expiry_if_due()?;
+        require!(self.contract.pending.is_empty(), CustomError::ConditionsNotMet);
+
+        let schedule = &self.schedule;
         let contract = &mut self.contract;
-        contract.execute()?;
-        
+        let weight = contract.execute(schedule)?;
+
         emit!(ContractExecuted {
             contract: contract.key(),
             execution_count: contract.execution_count,
             timestamp: contract.updated_at,
+            weight,
         });
-        
+
+        Ok(())
+    }
+}
+
+impl<'info> ExecuteContractWithParams<'info> {
+    pub fn process(&mut self, data: Vec<u8>) -> Result<()> {
+        require_not_paused(&self.program_state)?;
+        self.contract.apply_expiry_if_due()?;
+        require!(self.contract.pending.is_empty(), CustomError::ConditionsNotMet);
+
+        let params = decode_params(&self.contract.param_schema, &data)?;
+
+        let schedule = &self.schedule;
+        let contract = &mut self.contract;
+        let weight = contract.execute(schedule)?;
+
+        emit!(ContractExecutedWithParams {
+            contract: contract.key(),
+            execution_count: contract.execution_count,
+            timestamp: contract.updated_at,
+            weight,
+            params,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> ArmContract<'info> {
+    /// Stores a fresh release-condition plan, replacing any prior one.
+    pub fn process(&mut self, conditions: Vec<Condition>) -> Result<()> {
+        require!(conditions.len() <= MAX_PENDING_CONDITIONS, CustomError::InvalidInput);
+        for condition in &conditions {
+            validate_condition_depth(condition, 0)?;
+        }
+
+        self.contract.pending = conditions;
+        self.contract.updated_at = Clock::get()?.unix_timestamp;
+
+        Ok(())
+    }
+}
+
+impl<'info> ApplyWitness<'info> {
+    /// Collapses every pending condition against the current clock and the
+    /// signer's key, dropping any that become fully satisfied.
+    pub fn process(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let witness = self.witness.key();
+
+        let pending = std::mem::take(&mut self.contract.pending);
+        self.contract.pending = pending
+            .into_iter()
+            .filter_map(|condition| collapse_condition(condition, now, witness))
+            .collect();
+
+        self.contract.updated_at = now;
+
+        Ok(())
+    }
+}
+
+impl<'info> SetContractTimeout<'info> {
+    /// Arms (or clears, by passing `None`/`None`) the contract's timeout
+    /// deadline and the action `poke()`/`execute()` will apply once it's due.
+    pub fn process(&mut self, expires_at: Option<i64>, timeout_action: Option<TimeoutAction>) -> Result<()> {
+        self.contract.expires_at = expires_at;
+        self.contract.timeout_action = timeout_action;
+        self.contract.updated_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+}
+
+impl<'info> PokeContract<'info> {
+    /// Applies the contract's timeout action if its deadline has passed,
+    /// emitting `ContractExpired`. A no-op if there's no deadline, it hasn't
+    /// passed yet, or it already fired on a prior poke/execute.
+    pub fn process(&mut self) -> Result<()> {
+        if self.contract.apply_expiry_if_due()? {
+            emit!(ContractExpired {
+                contract: self.contract.key(),
+                is_active: self.contract.is_active,
+                authority: self.contract.authority,
+                timestamp: self.contract.updated_at,
+            });
+        }
         Ok(())
     }
 }
 
 impl<'info> BatchContractOperation<'info> {
+    /// Applies one status per remaining account (by position), skipping
+    /// entries that fail to deserialize as `Contract`, whose stored
+    /// authority doesn't match, or whose status is already the requested
+    /// one - preserving the original no-op economic optimization at N-contract
+    /// scale instead of a fixed pair.
     pub fn process_batch_status_update(
         &mut self,
+        remaining_accounts: &[AccountInfo<'info>],
         statuses: Vec<bool>,
     ) -> Result<()> {
-        require!(statuses.len() <= 2, CustomError::InvalidInput);
-        
-        let mut contracts = [&mut self.contract_1, &mut self.contract_2];
-
-        for (i, &is_active) in statuses.iter().enumerate() {
-            if i < contracts.len() {
-                let contract = &mut contracts[i];
-                if contract.is_active != is_active {
-                    contract.is_active = is_active;
-                    contract.updated_at = Clock::get()?.unix_timestamp;
-                    
-                    emit!(ContractStatusUpdated {
-                        contract: contract.key(),
-                        is_active,
-                        timestamp: contract.updated_at,
-                    });
-                }
+        require_not_paused(&self.program_state)?;
+        require!(statuses.len() <= BATCH_CONTRACT_LIMIT, CustomError::BatchOperationLimitExceeded);
+        require!(statuses.len() == remaining_accounts.len(), CustomError::InvalidInput);
+
+        let mut accounts_affected: u8 = 0;
+
+        for (account_info, &is_active) in remaining_accounts.iter().zip(statuses.iter()) {
+            let mut data = match account_info.try_borrow_mut_data() {
+                Ok(data) => data,
+                Err(_) => continue,
+            };
+
+            let mut contract = match Contract::try_deserialize(&mut data.as_ref()) {
+                Ok(contract) => contract,
+                Err(_) => continue,
+            };
+
+            if contract.authority != self.authority.key() {
+                continue;
+            }
+
+            if contract.is_active == is_active {
+                continue;
+            }
+
+            contract.is_active = is_active;
+            contract.updated_at = Clock::get()?.unix_timestamp;
+
+            if contract.try_serialize(&mut data.as_mut()).is_err() {
+                continue;
             }
+
+            accounts_affected += 1;
+
+            emit!(ContractStatusUpdated {
+                contract: *account_info.key,
+                is_active,
+                timestamp: contract.updated_at,
+            });
         }
-        
+
+        emit!(BatchOperationCompleted {
+            operation_type: "batch_contract_status_update".to_string(),
+            accounts_affected,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Increments `execution_count` across every remaining account that
+    /// deserializes as a `Contract` owned by `authority`, is currently
+    /// active (after applying any due timeout), has no pending release
+    /// conditions, and stays within the schedule's weight cap, skipping the
+    /// rest.
+    pub fn process_batch_execute(&mut self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        require_not_paused(&self.program_state)?;
+        require!(remaining_accounts.len() <= BATCH_CONTRACT_LIMIT, CustomError::BatchOperationLimitExceeded);
+
+        let mut accounts_affected: u8 = 0;
+
+        for account_info in remaining_accounts {
+            let mut data = match account_info.try_borrow_mut_data() {
+                Ok(data) => data,
+                Err(_) => continue,
+            };
+
+            let mut contract = match Contract::try_deserialize(&mut data.as_ref()) {
+                Ok(contract) => contract,
+                Err(_) => continue,
+            };
+
+            if contract.authority != self.authority.key() {
+                continue;
+            }
+
+            if contract.apply_expiry_if_due().is_err() {
+                continue;
+            }
+
+            if !contract.pending.is_empty() {
+                continue;
+            }
+
+            let weight = match contract.execute(&self.schedule) {
+                Ok(weight) => weight,
+                Err(_) => continue,
+            };
+
+            if contract.try_serialize(&mut data.as_mut()).is_err() {
+                continue;
+            }
+
+            accounts_affected += 1;
+
+            emit!(ContractExecuted {
+                contract: *account_info.key,
+                execution_count: contract.execution_count,
+                timestamp: contract.updated_at,
+                weight,
+            });
+        }
+
+        emit!(BatchOperationCompleted {
+            operation_type: "batch_contract_execute".to_string(),
+            accounts_affected,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
         Ok(())
     }
 }
@@ -29,6 +29,10 @@ pub enum CustomError {
     ContractTypeTooLong,
     #[msg("Contract is inactive")]
     ContractInactive,
+    #[msg("Sensor is not active (frozen or closed)")]
+    SensorNotActive,
+    #[msg("Sensor must be frozen before it can be closed")]
+    SensorNotFrozen,
     
     // General Validation Errors
     #[msg("Invalid input provided")]
@@ -49,4 +53,18 @@ pub enum CustomError {
     BatchOperationLimitExceeded,
     #[msg("Economic threshold not met for update")]
     EconomicThresholdNotMet,
+    #[msg("Program is paused")]
+    ProgramPaused,
+    #[msg("Contract has unsatisfied release conditions")]
+    ConditionsNotMet,
+    #[msg("Contract's accumulated execution weight exceeds its schedule limit")]
+    WeightExceeded,
+    #[msg("Execute call data does not match the contract's declared parameter schema")]
+    ParamDecodeError,
+    #[msg("Execute call data's type tag does not match the declared parameter schema")]
+    ParamTypeMismatch,
+    #[msg("Execute call data has fewer fields than the declared parameter schema")]
+    ParamArityMismatch,
+    #[msg("Execute call data has bytes left over after decoding the declared parameter schema")]
+    TrailingParamBytes,
 }
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::params::ParamValue;
 
 /// Event emitted when air quality sensor is initialized
 #[event]
@@ -20,6 +21,19 @@ pub struct AirQualityUpdated {
     pub humidity: f32,
     pub temperature: f32,
     pub timestamp: i64,
+    /// Bagged Merkle Mountain Range root after this reading was appended,
+    /// so light clients can later be handed an inclusion proof for it.
+    pub mmr_root: [u8; 32],
+}
+
+/// Event emitted when a frozen sensor's account is closed and its rent
+/// reclaimed, carrying the final accumulator root for anyone who needs to
+/// keep verifying past readings after the account is gone.
+#[event]
+pub struct AirQualitySealed {
+    pub air_quality: Pubkey,
+    pub final_mmr_root: [u8; 32],
+    pub timestamp: i64,
 }
 
 /// Event emitted when contract is initialized
@@ -57,6 +71,24 @@ pub struct ContractExecuted {
     pub contract: Pubkey,
     pub execution_count: u32,
     pub timestamp: i64,
+    /// Weight charged against this call, per the deployment's `Schedule`
+    /// (or [`crate::schedule::DEFAULT_EXECUTION_WEIGHT`] when the contract's
+    /// type has no entry in its weight table) -
+    /// mirrors Substrate attaching base weight to extrinsic events so
+    /// indexers can compute usage-based billing.
+    pub weight: u64,
+}
+
+/// Event emitted when `execute_with_params` decodes a call successfully,
+/// carrying the typed, decoded arguments so off-chain clients get a
+/// stable calling convention instead of having to re-parse `data` bytes.
+#[event]
+pub struct ContractExecutedWithParams {
+    pub contract: Pubkey,
+    pub execution_count: u32,
+    pub timestamp: i64,
+    pub weight: u64,
+    pub params: Vec<ParamValue>,
 }
 
 /// Event emitted for batch operations (economic optimization)
@@ -67,6 +99,27 @@ pub struct BatchOperationCompleted {
     pub timestamp: i64,
 }
 
+/// Event emitted each time `step_contract` advances (or closes) a
+/// contract's clause tree.
+#[event]
+pub struct ContractStepped {
+    pub contract: Pubkey,
+    pub is_active: bool,
+    pub closed: bool,
+    pub timestamp: i64,
+}
+
+/// Event emitted when `poke()` finds a contract past its `expires_at`
+/// deadline and applies its `timeout_action`. The expiry check the execute
+/// handlers run does not emit it.
+#[event]
+pub struct ContractExpired {
+    pub contract: Pubkey,
+    pub is_active: bool,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
 /// Event emitted when economic threshold prevents update
 #[event]
 pub struct EconomicThresholdNotMet {
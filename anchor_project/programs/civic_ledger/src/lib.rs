@@ -28,6 +28,16 @@ pub mod civic_ledger {
         Ok(())
     }
 
+    /// Update air quality data, gated by `is_significant_change_scheduled`
+    /// (reading live cuts off the `Schedule` PDA when one exists). Routes
+    /// through `AirQuality::update_data` so the rolling history buffer and
+    /// the Merkle Mountain Range accumulator both advance on this, the
+    /// primary update path, and `AirQualityUpdated.mmr_root` reflects a
+    /// real root rather than the zero value of an untouched accumulator.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the air quality account
+    /// * `aqi`, `pm25`, `pm10`, `co2`, `humidity`, `temperature` - The new reading
     pub fn update_air_quality(
         ctx: Context<UpdateAirQuality>,
         aqi: u16,
@@ -37,35 +47,7 @@ pub mod civic_ledger {
         humidity: f32,
         temperature: f32,
     ) -> Result<()> {
-        // Validate input ranges
-        require!(aqi <= 500, CustomError::InvalidAQIValue);
-        require!(pm25 >= 0.0 && pm25 <= 1000.0, CustomError::InvalidPM25Value);
-        require!(pm10 >= 0.0 && pm10 <= 1000.0, CustomError::InvalidPM10Value);
-        require!(co2 >= 0.0 && co2 <= 10000.0, CustomError::InvalidCO2Value);
-        require!(humidity >= 0.0 && humidity <= 100.0, CustomError::InvalidHumidityValue);
-        require!(temperature >= -50.0 && temperature <= 100.0, CustomError::InvalidTemperatureValue);
-
-        let air_quality = &mut ctx.accounts.air_quality;
-        air_quality.aqi = aqi;
-        air_quality.pm25 = pm25;
-        air_quality.pm10 = pm10;
-        air_quality.co2 = co2;
-        air_quality.humidity = humidity;
-        air_quality.temperature = temperature;
-        air_quality.updated_at = Clock::get()?.unix_timestamp;
-
-        emit!(AirQualityUpdated {
-            air_quality: air_quality.key(),
-            aqi,
-            pm25,
-            pm10,
-            co2,
-            humidity,
-            temperature,
-            timestamp: air_quality.updated_at,
-        });
-
-        Ok(())
+        ctx.accounts.process(aqi, pm25, pm10, co2, humidity, temperature)
     }
 
     pub fn initialize_contract(
@@ -73,46 +55,23 @@ pub mod civic_ledger {
         name: String,
         description: String,
         contract_type: String,
+        param_schema: Vec<ParamType>,
     ) -> Result<()> {
-        require!(name.len() <= 50, CustomError::NameTooLong);
-        require!(description.len() <= 200, CustomError::DescriptionTooLong);
-        require!(contract_type.len() <= 30, CustomError::ContractTypeTooLong);
-
-        let contract = &mut ctx.accounts.contract;
-        contract.name = name;
-        contract.description = description;
-        contract.contract_type = contract_type;
-        contract.authority = ctx.accounts.authority.key();
-        contract.is_active = true;
-        contract.created_at = Clock::get()?.unix_timestamp;
-        contract.updated_at = Clock::get()?.unix_timestamp;
-
-        emit!(ContractInitialized {
-            contract: contract.key(),
-            name: contract.name.clone(),
-            description: contract.description.clone(),
-            contract_type: contract.contract_type.clone(),
-            authority: contract.authority,
-        });
-
-        Ok(())
+        ctx.accounts.process(name, description, contract_type, param_schema)
     }
 
+    /// Update a contract's active status, gated by the `ProgramState`
+    /// kill-switch (a required account: the instruction fails until
+    /// `initialize_program_state` has been run).
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing accounts
+    /// * `is_active` - The new active status
     pub fn update_contract_status(
         ctx: Context<UpdateContract>,
         is_active: bool,
     ) -> Result<()> {
-        let contract = &mut ctx.accounts.contract;
-        contract.is_active = is_active;
-        contract.updated_at = Clock::get()?.unix_timestamp;
-
-        emit!(ContractStatusUpdated {
-            contract: contract.key(),
-            is_active,
-            timestamp: contract.updated_at,
-        });
-
-        Ok(())
+        ctx.accounts.process_status_update(is_active)
     }
 
     /// Update contract details with economic optimization
@@ -133,6 +92,246 @@ pub mod civic_ledger {
         ctx.accounts.process(name, description, contract_type)
     }
 
+    /// Creates the singleton `Schedule` PDA, seeded with the same defaults
+    /// that used to be hardcoded in `EconomicOptimizer`/`GasOptimizer`.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the schedule account and its governance payer
+    pub fn initialize_schedule(ctx: Context<InitializeSchedule>) -> Result<()> {
+        let bump = ctx.bumps.schedule;
+        ctx.accounts.process(bump)
+    }
+
+    /// Tunes an existing `Schedule`'s significance cuts, time tiers,
+    /// priority weights, and batch sizes. Only the PDA's stored
+    /// `governance` key may call this.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the schedule account and its governance signer
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_schedule(
+        ctx: Context<UpdateSchedule>,
+        aqi_significance_pct: f32,
+        pm25_significance_pct: f32,
+        pm10_significance_pct: f32,
+        co2_significance_pct: f32,
+        humidity_significance_abs: f32,
+        temperature_significance_abs: f32,
+        time_tier_short_secs: i64,
+        time_tier_medium_secs: i64,
+        time_tier_long_secs: i64,
+        priority_weight_aqi: u32,
+        priority_weight_pm25: u32,
+        priority_weight_pm10: u32,
+        priority_weight_co2: u32,
+        priority_weight_humidity: u32,
+        priority_weight_temperature: u32,
+        optimal_batch_size_air_quality: u8,
+        optimal_batch_size_contract: u8,
+    ) -> Result<()> {
+        ctx.accounts.process(
+            aqi_significance_pct,
+            pm25_significance_pct,
+            pm10_significance_pct,
+            co2_significance_pct,
+            humidity_significance_abs,
+            temperature_significance_abs,
+            time_tier_short_secs,
+            time_tier_medium_secs,
+            time_tier_long_secs,
+            priority_weight_aqi,
+            priority_weight_pm25,
+            priority_weight_pm10,
+            priority_weight_co2,
+            priority_weight_humidity,
+            priority_weight_temperature,
+            optimal_batch_size_air_quality,
+            optimal_batch_size_contract,
+        )
+    }
+
+    /// Sets the per-contract-type execution weight table and the overall
+    /// per-contract weight cap on the `Schedule` PDA, kept separate from
+    /// `update_schedule` so that call's parameter list doesn't grow further.
+    /// Only the PDA's stored `governance` key may call this.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the schedule account and its governance signer
+    /// * `contract_weights` - The new `contract_type -> execution_weight` table
+    /// * `max_weight_per_contract` - The cap a contract's `accumulated_weight` may not exceed
+    pub fn set_contract_weights(
+        ctx: Context<SetContractWeights>,
+        contract_weights: Vec<ContractWeight>,
+        max_weight_per_contract: u64,
+    ) -> Result<()> {
+        ctx.accounts.process(contract_weights, max_weight_per_contract)
+    }
+
+    /// Update air quality data, but only write to the account if the
+    /// reading moved at least one field past an explicit, caller-supplied
+    /// threshold (see [`AirQualityThresholds`]). Otherwise emits
+    /// `EconomicThresholdNotMet` and returns early without touching
+    /// `updated_at`, so near-identical readings from fixed sensors don't
+    /// pay transaction/compute cost every tick.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the air quality account
+    /// * `aqi`, `pm25`, `pm10`, `co2`, `humidity`, `temperature` - The new reading
+    /// * `thresholds` - Per-field minimum deltas that gate the write
+    pub fn update_air_quality_thresholded(
+        ctx: Context<UpdateAirQualityThresholded>,
+        aqi: u16,
+        pm25: f32,
+        pm10: f32,
+        co2: f32,
+        humidity: f32,
+        temperature: f32,
+        thresholds: AirQualityThresholds,
+    ) -> Result<()> {
+        ctx.accounts.process(aqi, pm25, pm10, co2, humidity, temperature, thresholds)
+    }
+
+    /// Batch update an arbitrary number of air quality sensors via
+    /// `remaining_accounts`, bounded by [`air_quality::BATCH_UPDATE_LIMIT`].
+    /// Entries that fail PDA/authority verification are skipped rather than
+    /// aborting the whole batch.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context; the target `AirQuality` PDAs are passed via `ctx.remaining_accounts`
+    /// * `readings` - One reading per remaining account, in the same order
+    pub fn update_air_quality_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateAirQualityBatch<'info>>,
+        readings: Vec<AirQualityReading>,
+    ) -> Result<()> {
+        ctx.accounts.process(ctx.remaining_accounts, readings)
+    }
+
+    /// Creates the singleton `ProgramState` kill-switch PDA, unpaused by
+    /// default.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the program state account and its governance payer
+    pub fn initialize_program_state(ctx: Context<InitializeProgramState>) -> Result<()> {
+        let bump = ctx.bumps.program_state;
+        ctx.accounts.process(bump)
+    }
+
+    /// Pauses every mutating contract instruction wired up to check
+    /// `ProgramState`, letting operators freeze the deployment during an
+    /// incident or migration without touching individual accounts.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the program state account and its governance signer
+    pub fn pause(ctx: Context<SetPaused>) -> Result<()> {
+        ctx.accounts.process(true)
+    }
+
+    /// Resumes the deployment after a `pause()`.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the program state account and its governance signer
+    pub fn resume(ctx: Context<SetPaused>) -> Result<()> {
+        ctx.accounts.process(false)
+    }
+
+    /// Freezes a sensor so no further `update_air_quality`/batch writes can
+    /// land on it, while its Merklized history stays queryable. The first
+    /// step of the retire → reclaim-rent lifecycle; only `close_air_quality`
+    /// can move a frozen sensor forward from here.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the air quality account and its authority
+    pub fn freeze_air_quality(ctx: Context<FreezeAirQuality>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    /// Closes a frozen sensor, emitting a final `AirQualitySealed` event with
+    /// its last accumulator root and returning the account's rent lamports
+    /// to the authority.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the air quality account and its authority
+    pub fn close_air_quality(ctx: Context<CloseAirQuality>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    /// Attaches a Marlowe-style clause tree to an already-initialized
+    /// `Contract`, letting it self-advance based on sensor observations and
+    /// timeouts instead of staying a static record. Rejects trees deeper
+    /// than [`marlowe::MAX_CLAUSE_DEPTH`].
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the new logic account and its target `Contract`
+    /// * `clause` - The root of the clause tree
+    /// * `sensor` - The `AirQuality` PDA any `If` observation in `clause` evaluates against
+    pub fn initialize_contract_logic(
+        ctx: Context<InitializeContractLogic>,
+        clause: Clause,
+        sensor: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.process(clause, sensor)
+    }
+
+    /// Advances a contract's clause tree by evaluating its current clause
+    /// against the live clock and, if present, a referenced `AirQuality`
+    /// account - applying every branch that doesn't require waiting until it
+    /// reaches a `When` still waiting on its cases, or a terminal `Close`.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the contract, its logic account, and an optional sensor account
+    pub fn step_contract(ctx: Context<StepContract>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    /// Arms a contract with a release-condition plan (payment-plan style,
+    /// after the Solana Budget contract) that `execute()` will refuse to run
+    /// past until every condition collapses away via `apply_witness`.
+    /// Replaces any prior plan. Bounded to [`contract::MAX_PENDING_CONDITIONS`]
+    /// top-level conditions, each no deeper than [`contract::MAX_CONDITION_DEPTH`].
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the contract account and its authority
+    /// * `conditions` - The release conditions that must all collapse before `execute` succeeds
+    pub fn arm_contract(ctx: Context<ArmContract>, conditions: Vec<Condition>) -> Result<()> {
+        ctx.accounts.process(conditions)
+    }
+
+    /// Applies a witness signature against a contract's pending conditions,
+    /// collapsing any `Signature` condition that matches the signer and any
+    /// `Timestamp` condition that's now due.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the contract account and the witness signer
+    pub fn apply_witness(ctx: Context<ApplyWitness>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    /// Arms (or clears) a contract's wall-clock timeout, after Marlowe's
+    /// timeout-continuation semantics: once `expires_at` passes, `poke()` or
+    /// the next `execute()` call applies `timeout_action` without needing
+    /// the authority online.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the contract account and its authority
+    /// * `expires_at` - The deadline, or `None` to clear it
+    /// * `timeout_action` - What to apply once the deadline passes, or `None` to clear it
+    pub fn set_contract_timeout(
+        ctx: Context<SetContractTimeout>,
+        expires_at: Option<i64>,
+        timeout_action: Option<TimeoutAction>,
+    ) -> Result<()> {
+        ctx.accounts.process(expires_at, timeout_action)
+    }
+
+    /// Permissionlessly applies a contract's timeout action once its
+    /// deadline has passed.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the contract account
+    pub fn poke(ctx: Context<PokeContract>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
     /// Execute a contract (increment usage counter)
     ///
     /// # Arguments
@@ -143,17 +342,44 @@ pub mod civic_ledger {
         ctx.accounts.process()
     }
 
-    /// Batch update contract statuses (economic optimization)
+    /// Execute a contract with ABI-style typed call data, decoded against
+    /// the contract's own declared `param_schema` (see [`params::decode_params`]).
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing accounts
+    /// * `data` - The call data, encoded per the contract's `param_schema`
+    pub fn execute_with_params(
+        ctx: Context<ExecuteContractWithParams>,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        ctx.accounts.process(data)
+    }
+
+    /// Batch update contract statuses (economic optimization), up to
+    /// [`contract::BATCH_CONTRACT_LIMIT`] contracts passed via
+    /// `ctx.remaining_accounts`.
     ///
     /// More cost-effective than individual updates when updating multiple contracts
     ///
     /// # Arguments
-    /// * `ctx` - The context containing multiple contract accounts
-    /// * `statuses` - Vector of new status values
-    pub fn batch_contract_status_update(
-        ctx: Context<BatchContractOperation>,
+    /// * `ctx` - The context; the target `Contract` PDAs are passed via `ctx.remaining_accounts`
+    /// * `statuses` - One status per remaining account, in the same order
+    pub fn batch_contract_status_update<'info>(
+        ctx: Context<'_, '_, '_, 'info, BatchContractOperation<'info>>,
         statuses: Vec<bool>,
     ) -> Result<()> {
-        ctx.accounts.process_batch_status_update(statuses)
+        ctx.accounts.process_batch_status_update(ctx.remaining_accounts, statuses)
+    }
+
+    /// Batch executes contracts (increments `execution_count`), up to
+    /// [`contract::BATCH_CONTRACT_LIMIT`] contracts passed via
+    /// `ctx.remaining_accounts`.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context; the target `Contract` PDAs are passed via `ctx.remaining_accounts`
+    pub fn batch_contract_execute<'info>(
+        ctx: Context<'_, '_, '_, 'info, BatchContractOperation<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.process_batch_execute(ctx.remaining_accounts)
     }
 }
\ No newline at end of file
@@ -0,0 +1,249 @@
+use anchor_lang::prelude::*;
+use crate::air_quality::AirQuality;
+use crate::contract::Contract;
+use crate::errors::CustomError;
+use crate::events::ContractStepped;
+
+/// Maximum depth of a clause tree, counted from the root. Bounds how much
+/// compute a single `step_contract` call can spend walking continuations.
+pub const MAX_CLAUSE_DEPTH: u8 = 8;
+
+/// Maximum number of `Case`s inside one `When`.
+pub const MAX_CASES: usize = 4;
+
+/// Upper bound on a clause tree's serialized size, used to size the
+/// `ContractLogic` account the same way `Contract::LEN` caps its strings.
+pub const MAX_CLAUSE_BYTES: usize = 1024;
+
+/// A comparison over a referenced `AirQuality` sensor's fields.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub enum Observation {
+    AqiGreaterThan(u16),
+    AqiLessThan(u16),
+    Pm25Between(f32, f32),
+    Pm10GreaterThan(f32),
+    Co2GreaterThan(f32),
+}
+
+impl Observation {
+    pub fn evaluate(&self, sensor: &AirQuality) -> bool {
+        match *self {
+            Observation::AqiGreaterThan(threshold) => sensor.aqi > threshold,
+            Observation::AqiLessThan(threshold) => sensor.aqi < threshold,
+            Observation::Pm25Between(lo, hi) => sensor.pm25 >= lo && sensor.pm25 <= hi,
+            Observation::Pm10GreaterThan(threshold) => sensor.pm10 > threshold,
+            Observation::Co2GreaterThan(threshold) => sensor.co2 > threshold,
+        }
+    }
+}
+
+/// What makes a `Case` eligible to fire.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub enum Action {
+    /// Eligible once the transaction's signer matches `role`.
+    Choice { role: Pubkey },
+    /// Eligible once `Clock::get()?.unix_timestamp >= at`.
+    TimeTrigger { at: i64 },
+}
+
+/// One branch of a `When`: an action paired with what happens once it fires.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct Case {
+    pub action: Action,
+    pub continuation: Box<Clause>,
+}
+
+/// A node in the contract's clause tree, modeled on Marlowe's core
+/// combinators: `When` waits for the first eligible case (or its timeout),
+/// `If` branches on a sensor observation, `SetActive` mutates the linked
+/// `Contract` and falls through, and `Close` ends the agreement.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub enum Clause {
+    When {
+        cases: Vec<Case>,
+        timeout: i64,
+        timeout_continuation: Box<Clause>,
+    },
+    If {
+        observation: Observation,
+        then: Box<Clause>,
+        or_else: Box<Clause>,
+    },
+    SetActive(bool, Box<Clause>),
+    Close,
+}
+
+/// Walks a clause tree, checking it's shallow enough for `step_contract` to
+/// ever safely evaluate and that no `When` offers more cases than `MAX_CASES`.
+pub fn validate_clause_depth(clause: &Clause, depth: u8) -> Result<()> {
+    require!(depth < MAX_CLAUSE_DEPTH, CustomError::InvalidInput);
+
+    match clause {
+        Clause::When { cases, timeout_continuation, .. } => {
+            require!(cases.len() <= MAX_CASES, CustomError::InvalidInput);
+            for case in cases {
+                validate_clause_depth(&case.continuation, depth + 1)?;
+            }
+            validate_clause_depth(timeout_continuation, depth + 1)
+        }
+        Clause::If { then, or_else, .. } => {
+            validate_clause_depth(then, depth + 1)?;
+            validate_clause_depth(or_else, depth + 1)
+        }
+        Clause::SetActive(_, continuation) => validate_clause_depth(continuation, depth + 1),
+        Clause::Close => Ok(()),
+    }
+}
+
+/// Tracks the in-progress clause tree for one `Contract`. Kept as a sibling
+/// PDA rather than folded into `Contract` itself, since `Contract::LEN` is a
+/// fixed byte count and a clause tree's size varies with its shape.
+#[account]
+pub struct ContractLogic {
+    pub contract: Pubkey,
+    pub authority: Pubkey,
+    /// The one `AirQuality` sensor any `If` in `current` observes. Bound at
+    /// init time so `step_contract` can verify the passed-in sensor account
+    /// rather than trusting whichever account the signer hands it.
+    pub sensor: Pubkey,
+    pub current: Clause,
+    pub closed: bool,
+}
+
+impl ContractLogic {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // contract
+        32 + // authority
+        32 + // sensor
+        MAX_CLAUSE_BYTES + // current (Clause, variable within this cap)
+        1; // closed
+}
+
+/// Context for attaching a clause tree to an already-initialized `Contract`.
+#[derive(Accounts)]
+pub struct InitializeContractLogic<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = ContractLogic::LEN,
+        seeds = [b"contract_logic", contract.key().as_ref()],
+        bump
+    )]
+    pub contract_logic: Account<'info, ContractLogic>,
+
+    #[account(has_one = authority @ CustomError::UnauthorizedAccess)]
+    pub contract: Account<'info, Contract>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for advancing a contract's clause tree by one or more steps.
+#[derive(Accounts)]
+pub struct StepContract<'info> {
+    #[account(
+        mut,
+        seeds = [b"contract_logic", contract.key().as_ref()],
+        bump,
+        has_one = contract @ CustomError::InvalidInput
+    )]
+    pub contract_logic: Account<'info, ContractLogic>,
+
+    #[account(mut)]
+    pub contract: Account<'info, Contract>,
+
+    /// The `AirQuality` account referenced by any `If` observation in the
+    /// current clause. Only needed when the current clause is an `If`; when
+    /// supplied, must match `contract_logic.sensor` so a passed-in account
+    /// can't be swapped for an unrelated sensor.
+    #[account(
+        constraint = air_quality.as_ref().map_or(true, |aq| aq.key() == contract_logic.sensor) @ CustomError::UnauthorizedAccess
+    )]
+    pub air_quality: Option<Account<'info, AirQuality>>,
+
+    /// Whoever is attempting to advance the contract; matched against any
+    /// `Action::Choice { role }` in the current clause's `When`.
+    pub signer: Signer<'info>,
+}
+
+impl<'info> InitializeContractLogic<'info> {
+    pub fn process(&mut self, clause: Clause, sensor: Pubkey) -> Result<()> {
+        validate_clause_depth(&clause, 0)?;
+
+        self.contract_logic.set_inner(ContractLogic {
+            contract: self.contract.key(),
+            authority: self.authority.key(),
+            sensor,
+            current: clause,
+            closed: false,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> StepContract<'info> {
+    /// Evaluates the current clause against the live `Clock` and, if
+    /// present, the passed-in sensor account, applying every branch that
+    /// doesn't require waiting for someone else until it lands on a `When`
+    /// still waiting on its cases, or a terminal `Close`.
+    pub fn process(&mut self) -> Result<()> {
+        require!(!self.contract_logic.closed, CustomError::ContractInactive);
+
+        let now = Clock::get()?.unix_timestamp;
+        let signer_key = self.signer.key();
+        let sensor = self.air_quality.as_deref();
+
+        let mut depth = 0u8;
+        loop {
+            require!(depth < MAX_CLAUSE_DEPTH, CustomError::InvalidInput);
+            depth += 1;
+
+            let next = match self.contract_logic.current.clone() {
+                Clause::Close => {
+                    self.contract_logic.closed = true;
+                    emit!(ContractStepped {
+                        contract: self.contract.key(),
+                        is_active: self.contract.is_active,
+                        closed: true,
+                        timestamp: now,
+                    });
+                    return Ok(());
+                }
+                Clause::SetActive(is_active, continuation) => {
+                    self.contract.is_active = is_active;
+                    self.contract.updated_at = now;
+                    *continuation
+                }
+                Clause::If { observation, then, or_else } => {
+                    let sensor = sensor.ok_or(CustomError::InvalidInput)?;
+                    if observation.evaluate(sensor) { *then } else { *or_else }
+                }
+                Clause::When { cases, timeout, timeout_continuation } => {
+                    let matched = cases.into_iter().find(|case| match &case.action {
+                        Action::Choice { role } => *role == signer_key,
+                        Action::TimeTrigger { at } => now >= *at,
+                    });
+
+                    match matched {
+                        Some(case) => *case.continuation,
+                        None if now >= timeout => *timeout_continuation,
+                        None => {
+                            emit!(ContractStepped {
+                                contract: self.contract.key(),
+                                is_active: self.contract.is_active,
+                                closed: false,
+                                timestamp: now,
+                            });
+                            return Ok(());
+                        }
+                    }
+                }
+            };
+
+            self.contract_logic.current = next;
+        }
+    }
+}
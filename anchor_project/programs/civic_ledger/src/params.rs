@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use crate::errors::CustomError;
+
+/// A contract's declared parameter schema, used to self-describe the
+/// calling convention for `execute_with_params` the way an ethabi ABI
+/// entry describes a Solidity function's argument list.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum ParamType {
+    U64,
+    I64,
+    Bool,
+    Pubkey,
+    Bytes,
+}
+
+/// Maximum number of fields a contract's `param_schema` may declare,
+/// bounding `Contract::LEN`.
+pub const MAX_PARAMS: usize = 8;
+pub const PARAM_TYPE_BYTES: usize = 1;
+
+/// Upper bound on the total size of an `execute_with_params` payload,
+/// capping the compute spent decoding a single call.
+pub const MAX_PARAM_DATA_BYTES: usize = 512;
+
+impl ParamType {
+    /// The 1-byte tag `execute_with_params` expects this field to be
+    /// encoded with, per the canonical layout documented on
+    /// [`decode_params`].
+    fn tag(&self) -> u8 {
+        match self {
+            ParamType::U64 => 0,
+            ParamType::I64 => 1,
+            ParamType::Bool => 2,
+            ParamType::Pubkey => 3,
+            ParamType::Bytes => 4,
+        }
+    }
+}
+
+/// A single decoded argument to `execute_with_params`, carried in the
+/// `ContractExecutedWithParams` event so off-chain clients don't have to
+/// re-decode the raw call data themselves.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub enum ParamValue {
+    U64(u64),
+    I64(i64),
+    Bool(bool),
+    Pubkey(Pubkey),
+    Bytes(Vec<u8>),
+}
+
+/// Decodes `data` against `schema`, rejecting anything that doesn't match
+/// the fixed, canonical calling convention: each field is a 1-byte type
+/// tag, a 4-byte little-endian length, then that many bytes of payload,
+/// in schema order. Fixed-width types (`U64`, `I64`, `Bool`, `Pubkey`)
+/// must carry exactly their natural length; `Bytes` may carry any length
+/// up to [`MAX_PARAM_DATA_BYTES`]. Arity is enforced by decoding exactly
+/// `schema.len()` fields and then requiring the cursor to have consumed
+/// every byte of `data` - so both too few fields and trailing bytes are
+/// rejected.
+pub fn decode_params(schema: &[ParamType], data: &[u8]) -> Result<Vec<ParamValue>> {
+    require!(data.len() <= MAX_PARAM_DATA_BYTES, CustomError::ParamDecodeError);
+
+    let mut cursor = 0usize;
+    let mut values = Vec::with_capacity(schema.len());
+
+    for expected in schema {
+        require!(cursor < data.len(), CustomError::ParamArityMismatch);
+        let tag = data[cursor];
+        require!(tag == expected.tag(), CustomError::ParamTypeMismatch);
+        cursor += 1;
+
+        require!(cursor + 4 <= data.len(), CustomError::ParamDecodeError);
+        let len = u32::from_le_bytes(data[cursor..cursor + 4].try_into().unwrap()) as usize;
+        cursor += 4;
+
+        require!(cursor + len <= data.len(), CustomError::ParamDecodeError);
+        let field = &data[cursor..cursor + len];
+        cursor += len;
+
+        let value = match expected {
+            ParamType::U64 => {
+                require!(len == 8, CustomError::ParamDecodeError);
+                ParamValue::U64(u64::from_le_bytes(field.try_into().unwrap()))
+            }
+            ParamType::I64 => {
+                require!(len == 8, CustomError::ParamDecodeError);
+                ParamValue::I64(i64::from_le_bytes(field.try_into().unwrap()))
+            }
+            ParamType::Bool => {
+                require!(len == 1, CustomError::ParamDecodeError);
+                ParamValue::Bool(field[0] != 0)
+            }
+            ParamType::Pubkey => {
+                require!(len == 32, CustomError::ParamDecodeError);
+                ParamValue::Pubkey(Pubkey::try_from(field).map_err(|_| CustomError::ParamDecodeError)?)
+            }
+            ParamType::Bytes => ParamValue::Bytes(field.to_vec()),
+        };
+
+        values.push(value);
+    }
+
+    require!(cursor == data.len(), CustomError::TrailingParamBytes);
+
+    Ok(values)
+}
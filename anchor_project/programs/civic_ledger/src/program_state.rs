@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use crate::errors::CustomError;
+
+/// Singleton kill-switch PDA (seeds `[b"program_state"]`), ported from the
+/// Aurora engine's "pause the contract" pattern: one governance-controlled
+/// flag that lets operators freeze contract initialization, status and
+/// detail updates, execution, and the batch contract operations during an
+/// incident or migration, without touching individual `Contract` accounts.
+/// The sensor instructions and the other contract handlers (arming,
+/// witnesses, timeouts, `poke`, `step_contract`) do not check it.
+#[account]
+pub struct ProgramState {
+    pub governance: Pubkey,
+    pub paused: bool,
+    pub bump: u8,
+}
+
+impl ProgramState {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // governance
+        1 + // paused
+        1; // bump
+}
+
+/// Context for creating the singleton `ProgramState` PDA.
+#[derive(Accounts)]
+pub struct InitializeProgramState<'info> {
+    #[account(
+        init,
+        payer = governance,
+        space = ProgramState::LEN,
+        seeds = [b"program_state"],
+        bump
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut)]
+    pub governance: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for flipping the pause flag. Guarded by the stored `governance`
+/// key so only that authority can pause/resume the deployment.
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_state"],
+        bump = program_state.bump,
+        has_one = governance @ CustomError::UnauthorizedAccess
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    pub governance: Signer<'info>,
+}
+
+impl<'info> InitializeProgramState<'info> {
+    pub fn process(&mut self, bump: u8) -> Result<()> {
+        self.program_state.set_inner(ProgramState {
+            governance: self.governance.key(),
+            paused: false,
+            bump,
+        });
+        Ok(())
+    }
+}
+
+impl<'info> SetPaused<'info> {
+    pub fn process(&mut self, paused: bool) -> Result<()> {
+        self.program_state.paused = paused;
+        Ok(())
+    }
+}
+
+/// Shared guard for every mutating handler this chunk wires up. Deployment
+/// must call `initialize_program_state` before any guarded instruction will
+/// run at all - unlike the `Option<Account<Schedule>>` fallback used for
+/// economic thresholds, the kill-switch has no meaning if it can be skipped,
+/// so `program_state` is a required account and Anchor itself rejects a
+/// missing or uninitialized one before this guard ever runs.
+pub fn require_not_paused(program_state: &ProgramState) -> Result<()> {
+    require!(!program_state.paused, CustomError::ProgramPaused);
+    Ok(())
+}
@@ -0,0 +1,231 @@
+use anchor_lang::prelude::*;
+use crate::errors::CustomError;
+
+/// A single `contract_type -> execution_weight` entry in a `Schedule`'s
+/// weight table, mirroring Substrate's per-extrinsic base-weight map.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct ContractWeight {
+    pub contract_type: String, // 30 chars max, matching Contract::contract_type
+    pub execution_weight: u64,
+}
+
+/// Maximum number of distinct contract types a `Schedule` can carry a
+/// weight for, bounding `Schedule::LEN`.
+pub const MAX_CONTRACT_WEIGHTS: usize = 8;
+const CONTRACT_WEIGHT_BYTES: usize = 4 + 30 + 8; // contract_type (String) + execution_weight (u64)
+
+/// Weight charged to a contract of this type when no entry matches in a
+/// `Schedule`'s `contract_weights` table - mirrors Substrate's minimum base
+/// weight per extrinsic.
+pub const DEFAULT_EXECUTION_WEIGHT: u64 = 1;
+
+/// Runtime-configurable cost/freshness knobs for the whole deployment.
+///
+/// Every threshold in `EconomicOptimizer`/`GasOptimizer` started life as a
+/// compile-time literal, so tuning a deployed city network's cost/freshness
+/// tradeoff meant a program upgrade. `Schedule` is a single PDA (seeds
+/// `[b"schedule"]`) holding the same knobs as a runtime-configurable struct,
+/// following Substrate's `pallet_contracts::Schedule` design - the
+/// weights/limits that govern execution cost are data, not code.
+#[account]
+pub struct Schedule {
+    /// Authority allowed to call `update_schedule`.
+    pub governance: Pubkey,
+
+    // --- EconomicOptimizer-equivalent significance cuts ---
+    pub aqi_significance_pct: f32,
+    pub pm25_significance_pct: f32,
+    pub pm10_significance_pct: f32,
+    pub co2_significance_pct: f32,
+    pub humidity_significance_abs: f32,
+    pub temperature_significance_abs: f32,
+
+    // --- EconomicOptimizer-equivalent time tiers ---
+    pub time_tier_short_secs: i64,  // was the hardcoded 6h cut
+    pub time_tier_medium_secs: i64, // was the hardcoded 12h cut
+    pub time_tier_long_secs: i64,   // was the hardcoded 24h cut
+
+    // --- EconomicOptimizer-equivalent priority weights ---
+    pub priority_weight_aqi: u32,
+    pub priority_weight_pm25: u32,
+    pub priority_weight_pm10: u32,
+    pub priority_weight_co2: u32,
+    pub priority_weight_humidity: u32,
+    pub priority_weight_temperature: u32,
+
+    // --- GasOptimizer-equivalent batch sizing ---
+    pub optimal_batch_size_air_quality: u8,
+    pub optimal_batch_size_contract: u8,
+
+    pub bump: u8,
+
+    // --- Per-contract-type execution weight schedule ---
+    pub contract_weights: Vec<ContractWeight>,
+    pub max_weight_per_contract: u64,
+}
+
+impl Schedule {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // governance
+        4 * 6 + // significance cuts
+        8 * 3 + // time tiers
+        4 * 6 + // priority weights
+        1 + 1 + // batch sizes
+        1 + // bump
+        4 + (CONTRACT_WEIGHT_BYTES * MAX_CONTRACT_WEIGHTS) + // contract_weights (capped)
+        8; // max_weight_per_contract
+
+    /// Looks up the execution weight for a contract type, falling back to
+    /// [`DEFAULT_EXECUTION_WEIGHT`] when no entry matches.
+    pub fn weight_for(&self, contract_type: &str) -> u64 {
+        self.contract_weights
+            .iter()
+            .find(|w| w.contract_type == contract_type)
+            .map(|w| w.execution_weight)
+            .unwrap_or(DEFAULT_EXECUTION_WEIGHT)
+    }
+
+    /// Defaults matching the literals `EconomicOptimizer`/`GasOptimizer`
+    /// shipped with before they became configurable.
+    pub fn defaults(governance: Pubkey, bump: u8) -> Self {
+        Self {
+            governance,
+            aqi_significance_pct: 5.0,
+            pm25_significance_pct: 10.0,
+            pm10_significance_pct: 10.0,
+            co2_significance_pct: 5.0,
+            humidity_significance_abs: 5.0,
+            temperature_significance_abs: 2.0,
+            time_tier_short_secs: 21_600,  // 6 hours
+            time_tier_medium_secs: 43_200, // 12 hours
+            time_tier_long_secs: 86_400,   // 24 hours
+            priority_weight_aqi: 10,
+            priority_weight_pm25: 8,
+            priority_weight_pm10: 6,
+            priority_weight_co2: 4,
+            priority_weight_humidity: 2,
+            priority_weight_temperature: 3,
+            optimal_batch_size_air_quality: 3,
+            optimal_batch_size_contract: 2,
+            bump,
+            contract_weights: Vec::new(),
+            max_weight_per_contract: u64::MAX,
+        }
+    }
+}
+
+/// Context for creating the singleton `Schedule` PDA.
+#[derive(Accounts)]
+pub struct InitializeSchedule<'info> {
+    #[account(
+        init,
+        payer = governance,
+        space = Schedule::LEN,
+        seeds = [b"schedule"],
+        bump
+    )]
+    pub schedule: Account<'info, Schedule>,
+
+    #[account(mut)]
+    pub governance: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for tuning an existing `Schedule`. Guarded by the stored
+/// `governance` key so only that authority can move these knobs.
+#[derive(Accounts)]
+pub struct UpdateSchedule<'info> {
+    #[account(
+        mut,
+        seeds = [b"schedule"],
+        bump = schedule.bump,
+        has_one = governance @ CustomError::UnauthorizedAccess
+    )]
+    pub schedule: Account<'info, Schedule>,
+
+    pub governance: Signer<'info>,
+}
+
+impl<'info> InitializeSchedule<'info> {
+    pub fn process(&mut self, bump: u8) -> Result<()> {
+        self.schedule.set_inner(Schedule::defaults(self.governance.key(), bump));
+        Ok(())
+    }
+}
+
+impl<'info> UpdateSchedule<'info> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn process(
+        &mut self,
+        aqi_significance_pct: f32,
+        pm25_significance_pct: f32,
+        pm10_significance_pct: f32,
+        co2_significance_pct: f32,
+        humidity_significance_abs: f32,
+        temperature_significance_abs: f32,
+        time_tier_short_secs: i64,
+        time_tier_medium_secs: i64,
+        time_tier_long_secs: i64,
+        priority_weight_aqi: u32,
+        priority_weight_pm25: u32,
+        priority_weight_pm10: u32,
+        priority_weight_co2: u32,
+        priority_weight_humidity: u32,
+        priority_weight_temperature: u32,
+        optimal_batch_size_air_quality: u8,
+        optimal_batch_size_contract: u8,
+    ) -> Result<()> {
+        let schedule = &mut self.schedule;
+
+        schedule.aqi_significance_pct = aqi_significance_pct;
+        schedule.pm25_significance_pct = pm25_significance_pct;
+        schedule.pm10_significance_pct = pm10_significance_pct;
+        schedule.co2_significance_pct = co2_significance_pct;
+        schedule.humidity_significance_abs = humidity_significance_abs;
+        schedule.temperature_significance_abs = temperature_significance_abs;
+        schedule.time_tier_short_secs = time_tier_short_secs;
+        schedule.time_tier_medium_secs = time_tier_medium_secs;
+        schedule.time_tier_long_secs = time_tier_long_secs;
+        schedule.priority_weight_aqi = priority_weight_aqi;
+        schedule.priority_weight_pm25 = priority_weight_pm25;
+        schedule.priority_weight_pm10 = priority_weight_pm10;
+        schedule.priority_weight_co2 = priority_weight_co2;
+        schedule.priority_weight_humidity = priority_weight_humidity;
+        schedule.priority_weight_temperature = priority_weight_temperature;
+        schedule.optimal_batch_size_air_quality = optimal_batch_size_air_quality;
+        schedule.optimal_batch_size_contract = optimal_batch_size_contract;
+
+        Ok(())
+    }
+}
+
+/// Context for tuning the per-contract-type execution weight table, kept
+/// separate from [`UpdateSchedule`] so that already-long parameter list
+/// doesn't grow further.
+#[derive(Accounts)]
+pub struct SetContractWeights<'info> {
+    #[account(
+        mut,
+        seeds = [b"schedule"],
+        bump = schedule.bump,
+        has_one = governance @ CustomError::UnauthorizedAccess
+    )]
+    pub schedule: Account<'info, Schedule>,
+
+    pub governance: Signer<'info>,
+}
+
+impl<'info> SetContractWeights<'info> {
+    pub fn process(&mut self, contract_weights: Vec<ContractWeight>, max_weight_per_contract: u64) -> Result<()> {
+        require!(contract_weights.len() <= MAX_CONTRACT_WEIGHTS, CustomError::InvalidInput);
+        for weight in &contract_weights {
+            require!(weight.contract_type.len() <= 30, CustomError::ContractTypeTooLong);
+        }
+
+        self.schedule.contract_weights = contract_weights;
+        self.schedule.max_weight_per_contract = max_weight_per_contract;
+
+        Ok(())
+    }
+}
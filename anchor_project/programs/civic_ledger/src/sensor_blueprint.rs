@@ -0,0 +1,169 @@
+use anchor_lang::prelude::*;
+
+/// A single field-level rule gating whether an incoming reading is
+/// significant enough to be worth a blockchain write.
+///
+/// `field_idx` indexes into the sensor's own, implementation-defined field
+/// order (see [`SensorBlueprint::field_value`]).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum ChangeRule {
+    /// Significant once the field moved by more than `pct` percent.
+    Percent { field_idx: u8, pct: f32 },
+    /// Significant once the field moved by more than `delta` in absolute terms.
+    Absolute { field_idx: u8, delta: f32 },
+    /// Significant once this many seconds have passed since the last write,
+    /// regardless of how much (or little) any field moved.
+    TimeSeconds(i64),
+}
+
+/// Implemented by any sensor account type that wants the
+/// validate / significant-change / batched-update / event-emission pipeline
+/// for free, instead of hand-rolling it the way `AirQuality` originally did.
+///
+/// `Fields` is the plain data of a single reading (what an instruction
+/// receives from a client); `Self` is the on-chain account storing the last
+/// accepted reading.
+pub trait SensorBlueprint: Sized {
+    type Fields: Copy;
+
+    /// Validates a reading's ranges before it's ever compared or stored.
+    fn validate(fields: &Self::Fields) -> Result<()>;
+
+    /// The fixed set of change rules gating writes for this sensor type.
+    fn thresholds() -> &'static [ChangeRule];
+
+    /// Reads one field (by the same index used in `thresholds()`) out of an
+    /// incoming reading, as an `f32` for distance comparisons.
+    fn field_value(fields: &Self::Fields, field_idx: u8) -> f32;
+
+    /// Reads the same field out of the account's currently stored reading.
+    fn current_value(&self, field_idx: u8) -> f32;
+
+    /// Timestamp of this account's last accepted write.
+    fn last_updated_at(&self) -> i64;
+
+    /// Writes `fields` into `self`. Called only after [`is_significant_change`]
+    /// has already confirmed the write is worthwhile.
+    fn apply(&mut self, fields: &Self::Fields) -> Result<()>;
+}
+
+/// Blanket significant-change check shared by every [`SensorBlueprint`]
+/// implementor: true once any configured [`ChangeRule`] fires.
+pub fn is_significant_change<B: SensorBlueprint>(account: &B, fields: &B::Fields) -> Result<bool> {
+    let now = Clock::get()?.unix_timestamp;
+
+    for rule in B::thresholds() {
+        let fires = match *rule {
+            ChangeRule::Percent { field_idx, pct } => {
+                let current = account.current_value(field_idx);
+                let incoming = B::field_value(fields, field_idx);
+                ((incoming - current).abs() / current.max(1.0)) * 100.0 > pct
+            }
+            ChangeRule::Absolute { field_idx, delta } => {
+                let current = account.current_value(field_idx);
+                let incoming = B::field_value(fields, field_idx);
+                (incoming - current).abs() > delta
+            }
+            ChangeRule::TimeSeconds(threshold) => now - account.last_updated_at() > threshold,
+        };
+
+        if fires {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+/// Generic batch-update helper for any [`SensorBlueprint`] account,
+/// generalizing the hand-unrolled `air_quality_1/2/3`-style fixed accounts
+/// into a single reusable pipeline.
+///
+/// Anchor's `#[derive(Accounts)]` can't express "N accounts of a generic
+/// type", so - mirroring `update_air_quality_batch` - the target accounts
+/// are passed in via `remaining_accounts` and bounded by `N` here rather
+/// than by the account struct itself.
+pub struct BatchUpdate<'a, 'info, B: SensorBlueprint, const N: usize> {
+    pub authority: Pubkey,
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+    _blueprint: core::marker::PhantomData<B>,
+}
+
+impl<'a, 'info, B, const N: usize> BatchUpdate<'a, 'info, B, N>
+where
+    B: SensorBlueprint + AccountSerialize + AccountDeserialize + Owner + Clone,
+{
+    pub fn new(authority: Pubkey, remaining_accounts: &'a [AccountInfo<'info>]) -> Self {
+        Self { authority, remaining_accounts, _blueprint: core::marker::PhantomData }
+    }
+
+    /// Applies one reading per remaining account (by position), skipping
+    /// entries that fail to deserialize as `B`, fail `verify_account` (e.g.
+    /// a PDA re-derivation check), whose stored authority doesn't match, or
+    /// that aren't significant enough to write. Returns the number of
+    /// accounts actually updated. `on_applied` runs once per account that
+    /// was actually written, so callers can emit their own concrete event
+    /// type (the generic pipeline has no event type of its own to emit).
+    pub fn process<F, V, E>(
+        &self,
+        readings: Vec<B::Fields>,
+        account_authority: F,
+        verify_account: V,
+        mut on_applied: E,
+    ) -> Result<u8>
+    where
+        F: Fn(&B) -> Pubkey,
+        V: Fn(&AccountInfo<'info>, &B) -> bool,
+        E: FnMut(&AccountInfo<'info>, &B),
+    {
+        require!(readings.len() <= N, crate::errors::CustomError::BatchOperationLimitExceeded);
+        require!(
+            readings.len() == self.remaining_accounts.len(),
+            crate::errors::CustomError::InvalidInput
+        );
+
+        let mut accounts_affected: u8 = 0;
+
+        for (account_info, fields) in self.remaining_accounts.iter().zip(readings.iter()) {
+            let mut data = match account_info.try_borrow_mut_data() {
+                Ok(data) => data,
+                Err(_) => continue,
+            };
+
+            let mut account = match B::try_deserialize(&mut data.as_ref()) {
+                Ok(account) => account,
+                Err(_) => continue,
+            };
+
+            if !verify_account(account_info, &account) {
+                continue;
+            }
+
+            if account_authority(&account) != self.authority {
+                continue;
+            }
+
+            if B::validate(fields).is_err() {
+                continue;
+            }
+
+            match is_significant_change(&account, fields) {
+                Ok(true) => {}
+                _ => continue,
+            }
+
+            if account.apply(fields).is_err() {
+                continue;
+            }
+
+            if account.try_serialize(&mut data.as_mut()).is_err() {
+                continue;
+            }
+
+            accounts_affected += 1;
+            on_applied(account_info, &account);
+        }
+
+        Ok(accounts_affected)
+    }
+}
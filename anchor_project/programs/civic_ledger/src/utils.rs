@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::schedule::Schedule;
 
 /// Economic optimization utilities
 pub struct EconomicOptimizer;
@@ -112,6 +113,58 @@ impl EconomicOptimizer {
 
         priority_score >= minimum_score
     }
+
+    /// Same scoring as [`calculate_update_priority`], but weighted by a live
+    /// [`Schedule`] when one is passed in, instead of the hardcoded weights.
+    /// Falls back to [`calculate_update_priority`] when `schedule` is `None`.
+    pub fn calculate_update_priority_scheduled(
+        aqi_change: f32,
+        pm25_change: f32,
+        pm10_change: f32,
+        co2_change: f32,
+        humidity_change: f32,
+        temp_change: f32,
+        time_since_update: i64,
+        schedule: Option<&Schedule>,
+    ) -> u32 {
+        let Some(schedule) = schedule else {
+            return Self::calculate_update_priority(
+                aqi_change, pm25_change, pm10_change, co2_change, humidity_change, temp_change, time_since_update,
+            );
+        };
+
+        let mut score = 0u32;
+
+        if aqi_change > schedule.aqi_significance_pct * 2.0 { score += schedule.priority_weight_aqi * 10; }
+        else if aqi_change > schedule.aqi_significance_pct { score += schedule.priority_weight_aqi * 5; }
+        else if aqi_change > schedule.aqi_significance_pct / 2.5 { score += schedule.priority_weight_aqi * 2; }
+
+        if pm25_change > schedule.pm25_significance_pct * 1.5 { score += schedule.priority_weight_pm25 * 10; }
+        else if pm25_change > schedule.pm25_significance_pct { score += schedule.priority_weight_pm25 * 5; }
+        else if pm25_change > schedule.pm25_significance_pct / 2.0 { score += schedule.priority_weight_pm25 * 2; }
+
+        if pm10_change > schedule.pm10_significance_pct * 2.0 { score += schedule.priority_weight_pm10 * 10; }
+        else if pm10_change > schedule.pm10_significance_pct * 1.5 { score += schedule.priority_weight_pm10 * 5; }
+        else if pm10_change > schedule.pm10_significance_pct { score += schedule.priority_weight_pm10 * 2; }
+
+        if co2_change > schedule.co2_significance_pct * 20.0 { score += schedule.priority_weight_co2 * 10; }
+        else if co2_change > schedule.co2_significance_pct * 10.0 { score += schedule.priority_weight_co2 * 5; }
+        else if co2_change > schedule.co2_significance_pct * 5.0 { score += schedule.priority_weight_co2 * 2; }
+
+        if humidity_change > schedule.humidity_significance_abs * 4.0 { score += schedule.priority_weight_humidity * 10; }
+        else if humidity_change > schedule.humidity_significance_abs * 2.0 { score += schedule.priority_weight_humidity * 5; }
+        else if humidity_change > schedule.humidity_significance_abs { score += schedule.priority_weight_humidity * 2; }
+
+        if temp_change > schedule.temperature_significance_abs * 5.0 { score += schedule.priority_weight_temperature * 10; }
+        else if temp_change > schedule.temperature_significance_abs * 2.5 { score += schedule.priority_weight_temperature * 5; }
+        else if temp_change > schedule.temperature_significance_abs { score += schedule.priority_weight_temperature * 2; }
+
+        if time_since_update > schedule.time_tier_long_secs { score += 100; }
+        else if time_since_update > schedule.time_tier_medium_secs { score += 50; }
+        else if time_since_update > schedule.time_tier_short_secs { score += 25; }
+
+        score
+    }
 }
 
 /// Validation utilities
@@ -189,4 +242,19 @@ impl GasOptimizer {
         let optimal_size = Self::optimal_batch_size(operation_type);
         operation_count >= optimal_size
     }
+
+    /// Same lookup as [`optimal_batch_size`], but reading the live
+    /// [`Schedule`] when one is passed in, instead of the hardcoded sizes.
+    /// Falls back to [`optimal_batch_size`] when `schedule` is `None`.
+    pub fn optimal_batch_size_scheduled(operation_type: &str, schedule: Option<&Schedule>) -> usize {
+        let Some(schedule) = schedule else {
+            return Self::optimal_batch_size(operation_type);
+        };
+
+        match operation_type {
+            "air_quality_update" => schedule.optimal_batch_size_air_quality as usize,
+            "contract_operation" => schedule.optimal_batch_size_contract as usize,
+            _ => 1,
+        }
+    }
 }
@@ -0,0 +1,21 @@
+use trident_fuzz::fuzzing::*;
+
+/// Bounded pools of previously-initialized accounts, keyed by the account
+/// index the fuzzer picks.
+///
+/// Rather than handing instructions a brand-new random `TridentPubkey` for
+/// every signer/PDA field (which almost always misses `has_one` checks and
+/// PDA-seed derivation), each field here is an `AccountsStorage` that maps a
+/// small index into a reused account. This means update instructions
+/// routinely reference an `air_quality` account that was actually created by
+/// a prior `initialize_air_quality` call, and an `authority` that sometimes
+/// matches the stored one and sometimes does not - exercising both the
+/// happy path and the `UnauthorizedAccess` path instead of bouncing off
+/// "account not found".
+#[derive(Default)]
+pub struct FuzzAccounts {
+    pub air_quality: AccountsStorage<PdaStore>,
+    pub contract: AccountsStorage<PdaStore>,
+    pub authority: AccountsStorage<KeypairStore>,
+    pub system_program: AccountsStorage<ProgramStore>,
+}
@@ -16,10 +16,10 @@ pub struct InitializeAirQualityInstruction {
 #[instruction_data(InitializeAirQualityInstructionData)]
 #[storage(FuzzAccounts)]
 pub struct InitializeAirQualityInstructionAccounts {
-    #[account(mut)]
+    #[account(mut, storage = fuzz_accounts.air_quality)]
     pub air_quality: TridentAccount,
 
-    #[account(mut, signer)]
+    #[account(mut, signer, storage = fuzz_accounts.authority)]
     pub authority: TridentAccount,
 
     #[account(address = "11111111111111111111111111111111")]
@@ -44,4 +44,21 @@ pub struct InitializeAirQualityInstructionData {
 /// Docs: https://ackee.xyz/trident/docs/latest/start-fuzzing/writting-fuzz-test/
 impl InstructionHooks for InitializeAirQualityInstruction {
     type IxAccounts = FuzzAccounts;
+
+    /// Registers the freshly created PDA and its authority in the bounded
+    /// account pools so later `update_air_quality` calls can pick this
+    /// account back up by index instead of inventing a new one.
+    fn post_ix(
+        &self,
+        fuzz_accounts: &mut Self::IxAccounts,
+    ) -> Result<(), FuzzingError> {
+        fuzz_accounts
+            .air_quality
+            .get_or_create_account(0, &[self.accounts.air_quality.pubkey()]);
+        fuzz_accounts
+            .authority
+            .get_or_create_account(0, &[self.accounts.authority.pubkey()]);
+
+        Ok(())
+    }
 }
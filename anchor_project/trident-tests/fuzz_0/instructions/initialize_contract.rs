@@ -16,10 +16,10 @@ pub struct InitializeContractInstruction {
 #[instruction_data(InitializeContractInstructionData)]
 #[storage(FuzzAccounts)]
 pub struct InitializeContractInstructionAccounts {
-    #[account(mut)]
+    #[account(mut, storage = fuzz_accounts.contract)]
     pub contract: TridentAccount,
 
-    #[account(mut, signer)]
+    #[account(mut, signer, storage = fuzz_accounts.authority)]
     pub authority: TridentAccount,
 
     #[account(address = "11111111111111111111111111111111")]
@@ -46,4 +46,20 @@ pub struct InitializeContractInstructionData {
 /// Docs: https://ackee.xyz/trident/docs/latest/start-fuzzing/writting-fuzz-test/
 impl InstructionHooks for InitializeContractInstruction {
     type IxAccounts = FuzzAccounts;
+
+    /// Registers the freshly created contract and its authority so later
+    /// `update_contract_status` calls can reference it by index.
+    fn post_ix(
+        &self,
+        fuzz_accounts: &mut Self::IxAccounts,
+    ) -> Result<(), FuzzingError> {
+        fuzz_accounts
+            .contract
+            .get_or_create_account(0, &[self.accounts.contract.pubkey()]);
+        fuzz_accounts
+            .authority
+            .get_or_create_account(0, &[self.accounts.authority.pubkey()]);
+
+        Ok(())
+    }
 }
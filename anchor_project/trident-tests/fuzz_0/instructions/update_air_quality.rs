@@ -16,10 +16,10 @@ pub struct UpdateAirQualityInstruction {
 #[instruction_data(UpdateAirQualityInstructionData)]
 #[storage(FuzzAccounts)]
 pub struct UpdateAirQualityInstructionAccounts {
-    #[account(mut)]
+    #[account(mut, storage = fuzz_accounts.air_quality)]
     pub air_quality: TridentAccount,
 
-    #[account(signer)]
+    #[account(signer, storage = fuzz_accounts.authority)]
     pub authority: TridentAccount,
 }
 
@@ -49,4 +49,58 @@ pub struct UpdateAirQualityInstructionData {
 /// Docs: https://ackee.xyz/trident/docs/latest/start-fuzzing/writting-fuzz-test/
 impl InstructionHooks for UpdateAirQualityInstruction {
     type IxAccounts = FuzzAccounts;
+
+    /// Asserts the on-chain post-state matches what this instruction
+    /// submitted, and that authorization was actually enforced, rather than
+    /// the fuzzer only ever hitting account-not-found errors.
+    fn check(
+        &self,
+        pre_ix: Vec<Option<AccountSnapshot>>,
+        post_ix: Vec<Option<AccountSnapshot>>,
+    ) -> Result<(), FuzzingError> {
+        let pre_air_quality = pre_ix[0]
+            .as_ref()
+            .and_then(|a| a.deserialize_data::<AirQuality>());
+        let Some(post_air_quality) = post_ix[0]
+            .as_ref()
+            .and_then(|a| a.deserialize_data::<AirQuality>())
+        else {
+            return Ok(());
+        };
+
+        let signer = self.accounts.authority.pubkey();
+
+        if let Some(pre_air_quality) = pre_air_quality {
+            if pre_air_quality.authority != signer {
+                // The update should have been rejected: nothing on-chain
+                // may have moved.
+                if post_air_quality != pre_air_quality {
+                    return Err(FuzzingError::Custom(1));
+                }
+                return Ok(());
+            }
+
+            if post_air_quality == pre_air_quality {
+                // Either the economic-threshold gate suppressed a
+                // no-op-sized change, or nothing changed - both fine.
+                return Ok(());
+            }
+
+            if post_air_quality.updated_at < pre_air_quality.created_at {
+                return Err(FuzzingError::Custom(2));
+            }
+        }
+
+        if post_air_quality.aqi != self.data.aqi
+            || post_air_quality.pm25 != self.data.pm25
+            || post_air_quality.pm10 != self.data.pm10
+            || post_air_quality.co2 != self.data.co2
+            || post_air_quality.humidity != self.data.humidity
+            || post_air_quality.temperature != self.data.temperature
+        {
+            return Err(FuzzingError::Custom(3));
+        }
+
+        Ok(())
+    }
 }
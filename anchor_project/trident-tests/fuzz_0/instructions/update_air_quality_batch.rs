@@ -0,0 +1,40 @@
+use crate::fuzz_accounts::FuzzAccounts;
+use crate::types::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use trident_fuzz::fuzzing::*;
+
+#[derive(TridentInstruction, Default)]
+#[program_id("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS")]
+#[discriminator([94u8, 209u8, 255u8, 234u8, 3u8, 26u8, 201u8, 4u8])]
+pub struct UpdateAirQualityBatchInstruction {
+    pub accounts: UpdateAirQualityBatchInstructionAccounts,
+    pub data: UpdateAirQualityBatchInstructionData,
+}
+
+/// Instruction Accounts
+///
+/// The target `AirQuality` PDAs are passed via `remaining_accounts` rather
+/// than named fields. The hooks below add no entries to it from the shared
+/// `air_quality` pool, so batch sizes vary only through the length of the
+/// fuzzed `readings` vector.
+#[derive(Debug, Clone, TridentAccounts, Default)]
+#[instruction_data(UpdateAirQualityBatchInstructionData)]
+#[storage(FuzzAccounts)]
+pub struct UpdateAirQualityBatchInstructionAccounts {
+    #[account(signer, storage = fuzz_accounts.authority)]
+    pub authority: TridentAccount,
+
+    #[remaining_accounts]
+    pub air_quality_accounts: RemainingAccounts,
+}
+
+/// Instruction Data
+#[derive(Debug, BorshDeserialize, BorshSerialize, Clone, Default)]
+pub struct UpdateAirQualityBatchInstructionData {
+    pub readings: Vec<AirQualityReading>,
+}
+
+/// Implementation of instruction setters for fuzzing
+impl InstructionHooks for UpdateAirQualityBatchInstruction {
+    type IxAccounts = FuzzAccounts;
+}
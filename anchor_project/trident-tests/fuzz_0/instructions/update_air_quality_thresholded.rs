@@ -0,0 +1,106 @@
+use crate::fuzz_accounts::FuzzAccounts;
+use crate::types::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use trident_fuzz::fuzzing::*;
+
+#[derive(TridentInstruction, Default)]
+#[program_id("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS")]
+#[discriminator([225u8, 132u8, 2u8, 12u8, 4u8, 70u8, 109u8, 196u8])]
+pub struct UpdateAirQualityThresholdedInstruction {
+    pub accounts: UpdateAirQualityThresholdedInstructionAccounts,
+    pub data: UpdateAirQualityThresholdedInstructionData,
+}
+
+/// Instruction Accounts
+#[derive(Debug, Clone, TridentAccounts, Default)]
+#[instruction_data(UpdateAirQualityThresholdedInstructionData)]
+#[storage(FuzzAccounts)]
+pub struct UpdateAirQualityThresholdedInstructionAccounts {
+    #[account(mut, storage = fuzz_accounts.air_quality)]
+    pub air_quality: TridentAccount,
+
+    #[account(signer, storage = fuzz_accounts.authority)]
+    pub authority: TridentAccount,
+}
+
+/// Instruction Data
+#[derive(Debug, BorshDeserialize, BorshSerialize, Clone, Default)]
+pub struct UpdateAirQualityThresholdedInstructionData {
+    pub aqi: u16,
+
+    pub pm25: f32,
+
+    pub pm10: f32,
+
+    pub co2: f32,
+
+    pub humidity: f32,
+
+    pub temperature: f32,
+
+    pub thresholds: AirQualityThresholds,
+}
+
+/// Implementation of instruction setters for fuzzing
+///
+/// Provides methods to:
+/// - Set instruction data during fuzzing
+/// - Configure instruction accounts during fuzzing
+/// - (Optional) Set remaining accounts during fuzzing
+///
+/// Docs: https://ackee.xyz/trident/docs/latest/start-fuzzing/writting-fuzz-test/
+impl InstructionHooks for UpdateAirQualityThresholdedInstruction {
+    type IxAccounts = FuzzAccounts;
+
+    /// Covers both branches of the economic-threshold gate: a reading that
+    /// doesn't clear any configured delta must leave the account (including
+    /// `updated_at`) untouched, while one that does must land exactly as
+    /// submitted without moving `updated_at` backwards.
+    fn check(
+        &self,
+        pre_ix: Vec<Option<AccountSnapshot>>,
+        post_ix: Vec<Option<AccountSnapshot>>,
+    ) -> Result<(), FuzzingError> {
+        let (Some(pre_air_quality), Some(post_air_quality)) = (
+            pre_ix[0].as_ref().and_then(|a| a.deserialize_data::<AirQuality>()),
+            post_ix[0].as_ref().and_then(|a| a.deserialize_data::<AirQuality>()),
+        ) else {
+            return Ok(());
+        };
+
+        if pre_air_quality.authority != self.accounts.authority.pubkey() {
+            // The update should have been rejected: nothing on-chain may
+            // have moved.
+            if post_air_quality != pre_air_quality {
+                return Err(FuzzingError::Custom(1));
+            }
+            return Ok(());
+        }
+
+        if post_air_quality == pre_air_quality {
+            // Suppressed branch: no configured delta was cleared, so
+            // `EconomicThresholdNotMet` fired and the account (including
+            // `updated_at`) is untouched.
+            return Ok(());
+        }
+
+        // Applied branch: at least one delta was cleared, so the reading
+        // must have landed exactly as submitted, with `updated_at` set to
+        // the current clock time (never earlier than the previous write).
+        if post_air_quality.updated_at < pre_air_quality.updated_at {
+            return Err(FuzzingError::Custom(2));
+        }
+
+        if post_air_quality.aqi != self.data.aqi
+            || post_air_quality.pm25 != self.data.pm25
+            || post_air_quality.pm10 != self.data.pm10
+            || post_air_quality.co2 != self.data.co2
+            || post_air_quality.humidity != self.data.humidity
+            || post_air_quality.temperature != self.data.temperature
+        {
+            return Err(FuzzingError::Custom(3));
+        }
+
+        Ok(())
+    }
+}
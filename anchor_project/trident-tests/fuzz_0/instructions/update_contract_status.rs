@@ -16,10 +16,10 @@ pub struct UpdateContractStatusInstruction {
 #[instruction_data(UpdateContractStatusInstructionData)]
 #[storage(FuzzAccounts)]
 pub struct UpdateContractStatusInstructionAccounts {
-    #[account(mut)]
+    #[account(mut, storage = fuzz_accounts.contract)]
     pub contract: TridentAccount,
 
-    #[account(signer)]
+    #[account(signer, storage = fuzz_accounts.authority)]
     pub authority: TridentAccount,
 }
 
@@ -39,4 +39,27 @@ pub struct UpdateContractStatusInstructionData {
 /// Docs: https://ackee.xyz/trident/docs/latest/start-fuzzing/writting-fuzz-test/
 impl InstructionHooks for UpdateContractStatusInstruction {
     type IxAccounts = FuzzAccounts;
+
+    /// Asserts a status update whose signer doesn't match the stored
+    /// authority is rejected rather than silently applied.
+    fn check(
+        &self,
+        pre_ix: Vec<Option<AccountSnapshot>>,
+        post_ix: Vec<Option<AccountSnapshot>>,
+    ) -> Result<(), FuzzingError> {
+        let (Some(pre_contract), Some(post_contract)) = (
+            pre_ix[0].as_ref().and_then(|a| a.deserialize_data::<Contract>()),
+            post_ix[0].as_ref().and_then(|a| a.deserialize_data::<Contract>()),
+        ) else {
+            return Ok(());
+        };
+
+        if pre_contract.authority != self.accounts.authority.pubkey()
+            && post_contract.is_active != pre_contract.is_active
+        {
+            return Err(FuzzingError::Custom(1));
+        }
+
+        Ok(())
+    }
 }
@@ -1,8 +1,12 @@
 pub mod initialize_air_quality;
 pub mod initialize_contract;
 pub mod update_air_quality;
+pub mod update_air_quality_batch;
+pub mod update_air_quality_thresholded;
 pub mod update_contract_status;
 pub use initialize_air_quality::*;
 pub use initialize_contract::*;
 pub use update_air_quality::*;
+pub use update_air_quality_batch::*;
+pub use update_air_quality_thresholded::*;
 pub use update_contract_status::*;
@@ -6,7 +6,27 @@ use trident_fuzz::fuzzing::*;
 ///
 /// You can define your own custom types here.
 
-#[derive(Debug, BorshDeserialize, BorshSerialize, Clone, Default)]
+/// A single historical sample in `AirQuality::history`'s rolling window.
+///
+/// Kept in sync with `civic_ledger::air_quality::AirQualityHistorySample`.
+#[derive(Debug, BorshDeserialize, BorshSerialize, Clone, Copy, Default, PartialEq)]
+pub struct AirQualityHistorySample {
+    pub aqi: u16,
+
+    pub pm25: f32,
+
+    pub pm10: f32,
+
+    pub co2: f32,
+
+    pub timestamp: i64,
+}
+
+/// Number of samples retained in `history`. Kept in sync with
+/// `civic_ledger::air_quality::HISTORY_CAPACITY`.
+pub const HISTORY_CAPACITY: usize = 24;
+
+#[derive(Debug, BorshDeserialize, BorshSerialize, Clone, Default, PartialEq)]
 pub struct AirQuality {
     pub location: String,
 
@@ -29,6 +49,64 @@ pub struct AirQuality {
     pub created_at: i64,
 
     pub updated_at: i64,
+
+    pub update_count: u32,
+
+    pub history: [AirQualityHistorySample; HISTORY_CAPACITY],
+
+    pub history_head: u8,
+
+    pub history_count: u8,
+
+    pub peaks: [[u8; 32]; MMR_MAX_PEAKS],
+
+    pub leaf_count: u64,
+
+    pub status: SensorStatus,
+}
+
+/// Maximum number of Merkle Mountain Range peaks. Kept in sync with
+/// `civic_ledger::air_quality::MMR_MAX_PEAKS`.
+pub const MMR_MAX_PEAKS: usize = 32;
+
+/// Kept in sync with `civic_ledger::air_quality::SensorStatus`.
+#[derive(Debug, BorshDeserialize, BorshSerialize, Clone, Copy, Default, PartialEq)]
+pub enum SensorStatus {
+    #[default]
+    Active,
+    Frozen,
+    Closed,
+}
+
+/// Kept in sync with `civic_ledger::air_quality::AirQualityThresholds`.
+#[derive(Debug, BorshDeserialize, BorshSerialize, Clone, Default, PartialEq)]
+pub struct AirQualityThresholds {
+    pub min_aqi_delta: u16,
+
+    pub min_pm25_delta: f32,
+
+    pub min_pm10_delta: f32,
+
+    pub min_co2_delta: f32,
+
+    pub min_humidity_delta: f32,
+
+    pub min_temperature_delta: f32,
+}
+
+#[derive(Debug, BorshDeserialize, BorshSerialize, Clone, Default, PartialEq)]
+pub struct AirQualityReading {
+    pub aqi: u16,
+
+    pub pm25: f32,
+
+    pub pm10: f32,
+
+    pub co2: f32,
+
+    pub humidity: f32,
+
+    pub temperature: f32,
 }
 
 #[derive(Debug, BorshDeserialize, BorshSerialize, Clone, Default)]
@@ -59,6 +137,8 @@ pub struct AirQualityUpdated {
     pub temperature: f32,
 
     pub timestamp: i64,
+
+    pub mmr_root: [u8; 32],
 }
 
 #[derive(Debug, BorshDeserialize, BorshSerialize, Clone, Default)]